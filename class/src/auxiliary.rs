@@ -1,134 +1,119 @@
 use super::Disciplina;
-use regex::Regex;
 use std::collections::BTreeSet;
-use std::ops::Deref;
-use stf::{Dia, SigaaTime, Turno};
+use stf::{ParseError, SigaaTime};
 
-#[derive(Debug)]
-enum AuxErrors {
-    TurnoNotFound,
-}
+/// Palavras ignoradas na abreviação.
+const PALAVRAS_EXCLUIDAS: [&str; 36] = ["a", "o", "as", "os", "e", "ou", "de", "da", "do", "das", "dos", "em", "na", "no", "nas", "nos", "ao", "aos", "à", "às", "para", "por", "pelo", "pela", "pelos", "pelas", "com", "sem", "sob", "sobre", "entre", "um", "uma", "uns", "umas", "the"];
+
+/// Algarismos romanos tratados como numeração (ex: "Cálculo II"), que entram inteiros na abreviação.
+const ROMANOS: [&str; 12] = ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII"];
 
 pub fn generate_abreviação(nome: &str) -> String {
-    let mut abreviação = String::new();
-    let palavras_excluidas = ["de", "em", "da", "do", "das", "dos"];
+    palavras(nome)
+        .iter()
+        .map(|palavra| palavra.parte(1))
+        .collect()
+}
 
-    for substring in nome.split_whitespace() {
-        if !palavras_excluidas.contains(&substring.to_lowercase().as_str()) {
-            if let Some(primeira_letra) = substring.chars().next() {
-                abreviação.push(primeira_letra.to_ascii_uppercase())
+/// Abreviações possíveis para o nome, da mais curta para a mais longa, começando por `generate_abreviação`.
+///
+/// As seguintes usam mais letras de uma das palavras, ex: "Cálculo Numérico" → `CN`, `CAN`, `CALN`, ..., `CNU`, ...
+pub fn abreviações_candidatas(nome: &str) -> Vec<String> {
+    let palavras = palavras(nome);
+    let mut candidatas = vec![generate_abreviação(nome)];
+
+    for (indice, palavra) in palavras.iter().enumerate() {
+        for letras in 2..=palavra.tamanho() {
+            let candidata: String = palavras
+                .iter()
+                .enumerate()
+                .map(|(outro, palavra)| match outro == indice {
+                    true => palavra.parte(letras),
+                    false => palavra.parte(1),
+                })
+                .collect();
+
+            if !candidatas.contains(&candidata) {
+                candidatas.push(candidata);
             }
         }
     }
 
-    abreviação
+    candidatas
 }
-pub fn generate_horario_display(disciplina: &Disciplina) -> String {
-    let mut output = String::new();
-    let mut dias: BTreeSet<Dia> = BTreeSet::new();
-    let mut turnos: BTreeSet<Turno> = BTreeSet::new();
 
-    for elem in disciplina.sigaa_time.iter() {
-        dias.insert(elem.dia);
-        turnos.insert(elem.turno);
-    }
-
-    for dia in dias.iter() {
-        output = format!("{}{}", output, dia);
-    }
-
-    for (i, turno) in turnos.iter().enumerate() {
-        match i {
-            0 => output = format!("{}{}", output, turno),
-            _ => output = format!("{}{}", output, &<Turno as Into<String>>::into(*turno)[1..]),
-        }
-    }
-    output
+/// Palavra significativa de um nome de disciplina.
+enum Palavra {
+    /// Palavra comum, já em maiúsculas e sem acentos.
+    Texto(Vec<char>),
+    /// Numeração (romana ou arábica), que entra inteira.
+    Numero(String),
 }
 
-pub fn is_formatted(time: &str) -> bool {
-    let regex = Regex::new(r"^(\d{1,5})([MTN])(\d{2,6})$").unwrap();
-
-    if let Some(captura) = regex.captures(time) {
-        let dd = &captura[1];
-        let hhhh = &captura[3];
-
-        for elem in dd.chars() {
-            if let Some(dd_num) = elem.to_digit(10) {
-                if !(2..=7).contains(&dd_num) {
-                    return false;
-                }
-            } else {
-                return false;
-            }
+impl Palavra {
+    fn parte(&self, letras: usize) -> String {
+        match self {
+            Palavra::Texto(texto) => texto.iter().take(letras).collect(),
+            Palavra::Numero(numero) => numero.clone(),
         }
+    }
 
-        if hhhh
-            .chars()
-            .all(|c| c.is_ascii_digit() && ('1'..='6').contains(&c))
-        {
-            return true;
+    fn tamanho(&self) -> usize {
+        match self {
+            Palavra::Texto(texto) => texto.len(),
+            Palavra::Numero(_) => 1,
         }
     }
-
-    false
 }
 
-pub fn valid_string_to_vec_sigaa_times(valid_string: &str) -> BTreeSet<SigaaTime> {
-    breakdown(valid_string, &mut BTreeSet::new())
+fn palavras(nome: &str) -> Vec<Palavra> {
+    nome.split_whitespace()
+        .filter(|palavra| !PALAVRAS_EXCLUIDAS.contains(&palavra.to_lowercase().as_str()))
+        .filter_map(|palavra| {
+            let palavra = palavra.trim_matches(|c: char| !c.is_alphanumeric());
+
+            match palavra.is_empty() {
+                true => None,
+                false if ROMANOS.contains(&palavra) || palavra.chars().all(|c| c.is_ascii_digit()) => Some(Palavra::Numero(palavra.to_string())),
+                false => Some(Palavra::Texto(
+                    palavra
+                        .chars()
+                        .filter(|c| c.is_alphanumeric())
+                        .map(sem_acento)
+                        .collect(),
+                )),
+            }
+        })
+        .collect()
 }
 
-fn breakdown(value: &str, vec: &mut BTreeSet<SigaaTime>) -> BTreeSet<SigaaTime> {
-    let days = extract_day(value).len();
-
-    match days {
-        1 if value.len() == 4 => {
-            vec.insert(value.try_into().unwrap());
-        }
-        1 => hour_breaker(value, vec),
-        _ => day_breaker(days, value, vec),
+/// Letra maiúscula sem acento.
+fn sem_acento(letra: char) -> char {
+    match letra.to_uppercase().next().unwrap_or(letra) {
+        'Á' | 'À' | 'Â' | 'Ã' | 'Ä' => 'A',
+        'É' | 'È' | 'Ê' | 'Ë' => 'E',
+        'Í' | 'Ì' | 'Î' | 'Ï' => 'I',
+        'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ö' => 'O',
+        'Ú' | 'Ù' | 'Û' | 'Ü' => 'U',
+        'Ç' => 'C',
+        maiuscula => maiuscula,
     }
-
-    vec.deref().clone()
 }
 
-fn day_breaker(loops: usize, value: &str, vec: &mut BTreeSet<SigaaTime>) {
-    for i in 0..loops {
-        let break_value: String = value
-            .chars()
-            .enumerate()
-            .filter(|(index, _)| *index != i)
-            .map(|(_, c)| c)
-            .collect();
-        breakdown(&break_value, vec);
-    }
+/// Horário da disciplina no formato do SIGAA, com a menor quantidade de códigos (ex: `2M12 4T34`).
+pub fn generate_horario_display(disciplina: &Disciplina) -> String {
+    SigaaTime::format_codes(&disciplina.sigaa_time)
 }
 
-fn hour_breaker(value: &str, vec: &mut BTreeSet<SigaaTime>) {
-    let prefix = &value[..2];
-    let first_suffix = &value[2..4];
-    let last_suffix = &value[4..];
-
-    let first_part = format!("{}{}", prefix, first_suffix);
-    let last_part = format!("{}{}", prefix, last_suffix);
-
-    breakdown(&first_part, vec);
-    breakdown(&last_part, vec);
-}
-
-fn extract_day(texto: &str) -> &str {
-    let finder = texto.find(|c| c == 'T' || c == 'M' || c == 'N');
-
-    match finder {
-        Some(pos) => texto.split_at(pos).0,
-        None => panic!("{:?}: {}", AuxErrors::TurnoNotFound, texto),
-    }
+pub fn valid_string_to_vec_sigaa_times(valid_string: &str) -> Result<BTreeSet<SigaaTime>, ParseError> {
+    SigaaTime::parse_codes(valid_string)
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::{Atividade, DisciplinaErrors};
 
     #[test]
     fn should_generate_a_correct_abrv() {
@@ -136,25 +121,99 @@ mod tests {
         assert_eq!(abv, "AMFT")
     }
 
+    #[test]
+    fn should_keep_numerals_and_drop_accents_and_stopwords() {
+        assert_eq!(generate_abreviação("Cálculo I"), "CI");
+        assert_eq!(generate_abreviação("Cálculo II"), "CII");
+        assert_eq!(generate_abreviação("Álgebra Linear 2"), "AL2");
+        assert_eq!(generate_abreviação("Introdução à Programação para Engenharia"), "IPE");
+        assert_eq!(generate_abreviação("Física - Laboratório"), "FL");
+        assert_eq!(generate_abreviação("ENGENHARIA CIVIL"), "EC");
+    }
+
+    #[test]
+    fn should_list_longer_candidates_after_the_initials() {
+        let candidatas = abreviações_candidatas("Cálculo Numérico");
+
+        assert_eq!(candidatas[..3], ["CN", "CAN", "CALN"]);
+        assert!(candidatas.contains(&"CNU".to_string()));
+        assert_eq!(abreviações_candidatas("Cálculo II")[..2], ["CII", "CAII"]);
+    }
+
     #[test]
     fn should_return_true_with_formatted_strings() {
-        assert!(is_formatted("246T56"));
-        assert!(is_formatted("2T12"));
-        assert!(is_formatted("5N34"));
-        assert!(is_formatted("36M12"));
-        assert!(is_formatted("246M56"));
+        assert!(valid_string_to_vec_sigaa_times("246T56").is_ok());
+        assert!(valid_string_to_vec_sigaa_times("2T12").is_ok());
+        assert!(valid_string_to_vec_sigaa_times("5N34").is_ok());
+        assert!(valid_string_to_vec_sigaa_times("36M12").is_ok());
+        assert!(valid_string_to_vec_sigaa_times("246M56").is_ok());
+        assert!(valid_string_to_vec_sigaa_times("1234T1234").is_ok());
     }
 
     #[test]
     fn should_return_false_with_non_formatted_strings() {
-        assert!(!is_formatted("24X34"));
-        assert!(!is_formatted("29M12"));
-        assert!(!is_formatted("1234T1234"));
+        assert!(valid_string_to_vec_sigaa_times("24X34").is_err());
+        assert!(valid_string_to_vec_sigaa_times("29M12").is_err());
+        assert!(valid_string_to_vec_sigaa_times("8234T1234").is_err());
+        assert!(valid_string_to_vec_sigaa_times("2M").is_err());
+        assert!(valid_string_to_vec_sigaa_times("2T4").is_err());
+        assert!(valid_string_to_vec_sigaa_times("").is_err());
+    }
+
+    #[test]
+    fn should_display_the_optional_class_data() {
+        let mut disciplina = Disciplina::new_stringify("Cálculo I", "24M12").unwrap();
+        assert_eq!(disciplina.to_string(), "Cálculo I - CI - 24M12");
+
+        disciplina.codigo = Some("MAT0311".to_string());
+        disciplina.turma = Some("03".to_string());
+        disciplina.local = Some("Setor II".to_string());
+        disciplina.professores = vec!["Ana".to_string(), "Bruno".to_string()];
+
+        assert_eq!(disciplina.to_string(), "MAT0311 - Cálculo I (turma 03) - CI - 24M12 - Setor II - Ana, Bruno");
+        assert_eq!(format!("{:?}", disciplina), "CI (MAT0311-T03) - 2M12 4M12");
+    }
+
+    #[test]
+    fn should_only_detail_times_of_the_discipline() {
+        let mut disciplina = Disciplina::new_stringify("Física", "2M12").unwrap();
+        let outro_horario = SigaaTime::new_from_strings("3", "M12").unwrap();
+
+        assert!(matches!(disciplina.set_local(outro_horario, "Lab"), Err(DisciplinaErrors::TimeNotInDiscipline(_))));
+        assert!(disciplina.detalhes.is_empty());
+
+        let horario = SigaaTime::new_from_strings("2", "M12").unwrap();
+        disciplina.set_local(horario, "Lab").unwrap();
+        disciplina
+            .set_atividade(horario, Atividade::Laboratorio)
+            .unwrap();
+        assert_eq!(disciplina.detalhes.len(), 1);
+    }
+
+    #[test]
+    fn should_accept_compound_codes() {
+        assert!(valid_string_to_vec_sigaa_times("24M12 6T34").is_ok());
+        assert!(valid_string_to_vec_sigaa_times("2M56T12").is_ok());
+
+        let sigaa_times = valid_string_to_vec_sigaa_times("24M12 6T34").unwrap();
+        assert_eq!(sigaa_times.len(), 3);
+        assert!(sigaa_times.contains(&SigaaTime::new_from_strings("6", "T34").unwrap()));
+    }
+
+    #[test]
+    fn should_point_to_the_invalid_part_of_the_code() {
+        let erro = valid_string_to_vec_sigaa_times("24M12 6X34").unwrap_err();
+        assert_eq!(erro.kind, stf::ParseErrorKind::InvalidTurno('X'));
+        assert_eq!(erro.span, 7..8);
+
+        let erro = valid_string_to_vec_sigaa_times("35M23").unwrap_err();
+        assert_eq!(erro.kind, stf::ParseErrorKind::PeriodosNotPaired);
+        assert_eq!(erro.span, 0..5);
     }
 
     #[test]
     fn should_create_a_correct_list_of_sigaa_time_days() {
-        let sigaa_times = valid_string_to_vec_sigaa_times("246T12");
+        let sigaa_times = valid_string_to_vec_sigaa_times("246T12").unwrap();
 
         let sigaa_time_1 = SigaaTime::new_from_strings("2", "T12").unwrap();
         let sigaa_time_2 = SigaaTime::new_from_strings("4", "T12").unwrap();
@@ -169,7 +228,7 @@ mod tests {
 
     #[test]
     fn should_create_a_correct_list_of_sigaa_time_hour() {
-        let sigaa_times = valid_string_to_vec_sigaa_times("2T123456");
+        let sigaa_times = valid_string_to_vec_sigaa_times("2T123456").unwrap();
         let sigaa_time_1 = SigaaTime::new_from_strings("2", "T12").unwrap();
         let sigaa_time_2 = SigaaTime::new_from_strings("2", "T34").unwrap();
         let sigaa_time_3 = SigaaTime::new_from_strings("2", "T56").unwrap();
@@ -183,7 +242,7 @@ mod tests {
 
     #[test]
     fn should_create_a_correct_list_of_sigaa_time_days_and_hours() {
-        let sigaa_times = valid_string_to_vec_sigaa_times("246T1234");
+        let sigaa_times = valid_string_to_vec_sigaa_times("246T1234").unwrap();
         let sigaa_time_1 = SigaaTime::new_from_strings("2", "T12").unwrap();
         let sigaa_time_2 = SigaaTime::new_from_strings("2", "T34").unwrap();
         let sigaa_time_3 = SigaaTime::new_from_strings("4", "T12").unwrap();
@@ -1,8 +1,8 @@
 use super::auxiliary::*;
-use super::{Disciplina, DisciplinaErrors};
+use super::{Atividade, DetalheHorario, Disciplina, DisciplinaErrors};
 use std::collections::BTreeSet;
 use std::fmt::{self, Display};
-use stf::SigaaTime;
+use stf::{Bloco, SigaaSlot, SigaaTime, TabelaHorarios};
 
 impl Disciplina {
     pub fn new(nome: &str, sigaa_time: BTreeSet<SigaaTime>) -> Disciplina {
@@ -10,21 +10,20 @@ impl Disciplina {
             sigaa_time,
             abreviacao: generate_abreviação(nome),
             nome: nome.to_string(),
+            codigo: None,
+            turma: None,
+            professores: vec![],
+            local: None,
+            creditos: None,
+            carga_horaria: None,
+            detalhes: vec![],
         }
     }
 
     pub fn new_stringify(nome: &str, new_time: &str) -> Result<Disciplina, DisciplinaErrors> {
-        if !is_formatted(new_time) {
-            return Err(DisciplinaErrors::TimeNotFormatted);
-        }
-
-        let new_times: BTreeSet<SigaaTime> = valid_string_to_vec_sigaa_times(new_time);
+        let new_times: BTreeSet<SigaaTime> = valid_string_to_vec_sigaa_times(new_time).map_err(DisciplinaErrors::TimeNotFormatted)?;
 
-        Ok(Disciplina {
-            sigaa_time: new_times,
-            abreviacao: generate_abreviação(nome),
-            nome: nome.to_string(),
-        })
+        Ok(Disciplina::new(nome, new_times))
     }
 
     pub fn add_time(&mut self, new_time: SigaaTime) -> Result<(), DisciplinaErrors> {
@@ -33,23 +32,202 @@ impl Disciplina {
             false => Err(DisciplinaErrors::TimeAlreadyInserted),
         }
     }
+
+    /// Todos os períodos individuais ocupados pela disciplina.
+    pub fn slots(&self) -> BTreeSet<SigaaSlot> {
+        self.sigaa_time
+            .iter()
+            .flat_map(|sigaa_time| sigaa_time.slots())
+            .collect()
+    }
+
+    /// Blocos de aula contínuos da disciplina, com início e fim no relógio.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use stf::TabelaHorarios;
+    /// let disciplina = Disciplina::new_stringify("Cálculo I", "24M12").unwrap();
+    /// let blocos = disciplina.blocos(&TabelaHorarios::default());
+    /// assert_eq!(blocos[0].to_string(), "2 07:00-08:40");
+    /// assert_eq!(blocos[1].to_string(), "4 07:00-08:40");
+    /// ```
+    pub fn blocos(&self, tabela: &TabelaHorarios) -> Vec<Bloco> {
+        tabela.blocos(&self.slots())
+    }
+
+    /// Janelas entre aulas consecutivas da disciplina no mesmo dia.
+    pub fn janelas(&self, tabela: &TabelaHorarios) -> Vec<Bloco> {
+        tabela.janelas(&self.slots())
+    }
+
+    /// Tempo de aula por semana, em minutos.
+    pub fn duracao_semanal_minutos(&self, tabela: &TabelaHorarios) -> u32 {
+        tabela.duracao_total_minutos(&self.slots())
+    }
+
+    /// Abreviações possíveis para o nome da disciplina, da preferida para a mais longa.
+    ///
+    /// Usado pelo cronograma para escolher uma abreviação que não se repita entre as disciplinas.
+    pub fn abreviacoes_candidatas(&self) -> Vec<String> {
+        abreviações_candidatas(&self.nome)
+    }
+
+    /// Local das aulas num horário: o do próprio horário, se houver, ou o da disciplina.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::{Atividade, Disciplina};
+    /// use stf::SigaaTime;
+    ///
+    /// let mut fisica = Disciplina::new_stringify("Física I", "2M12 5T34").unwrap();
+    /// fisica.local = Some("Setor II - A2".to_string());
+    ///
+    /// let quinta = SigaaTime::try_from("5T34").unwrap();
+    /// fisica.set_local(quinta, "Laboratório 3").unwrap();
+    /// fisica.set_atividade(quinta, Atividade::Laboratorio).unwrap();
+    ///
+    /// assert_eq!(fisica.local_em(&quinta), Some("Laboratório 3"));
+    /// assert_eq!(fisica.local_em(&SigaaTime::try_from("2M12").unwrap()), Some("Setor II - A2"));
+    /// assert_eq!(fisica.atividade_em(&quinta), Atividade::Laboratorio);
+    /// ```
+    pub fn local_em(&self, horario: &SigaaTime) -> Option<&str> {
+        self.detalhe(horario)
+            .and_then(|detalhe| detalhe.local.as_deref())
+            .or(self.local.as_deref())
+    }
+
+    /// Tipo de atividade num horário (`Atividade::Teorica` se não foi informado).
+    pub fn atividade_em(&self, horario: &SigaaTime) -> Atividade {
+        self.detalhe(horario)
+            .map(|detalhe| detalhe.atividade)
+            .unwrap_or_default()
+    }
+
+    /// Define o local de um horário da disciplina.
+    pub fn set_local(&mut self, horario: SigaaTime, local: &str) -> Result<(), DisciplinaErrors> {
+        self.detalhe_mut(horario)?.local = Some(local.to_string());
+        Ok(())
+    }
+
+    /// Define o tipo de atividade de um horário da disciplina.
+    pub fn set_atividade(&mut self, horario: SigaaTime, atividade: Atividade) -> Result<(), DisciplinaErrors> {
+        self.detalhe_mut(horario)?.atividade = atividade;
+        Ok(())
+    }
+
+    fn detalhe(&self, horario: &SigaaTime) -> Option<&DetalheHorario> {
+        self.detalhes
+            .iter()
+            .find(|detalhe| detalhe.horario == *horario)
+    }
+
+    /// Detalhe do horário, criado com os valores padrão se ainda não existir.
+    fn detalhe_mut(&mut self, horario: SigaaTime) -> Result<&mut DetalheHorario, DisciplinaErrors> {
+        if !self.sigaa_time.contains(&horario) {
+            return Err(DisciplinaErrors::TimeNotInDiscipline(horario));
+        }
+
+        let indice = match self
+            .detalhes
+            .iter()
+            .position(|detalhe| detalhe.horario == horario)
+        {
+            Some(indice) => indice,
+            None => {
+                self.detalhes
+                    .push(DetalheHorario { horario, local: None, atividade: Atividade::default() });
+                self.detalhes.len() - 1
+            }
+        };
+
+        Ok(&mut self.detalhes[indice])
+    }
+}
+
+/// Duas disciplinas são iguais quando têm os mesmos dados, independente da abreviação, que é só um
+/// rótulo e pode ser trocada pelo cronograma para não se repetir.
+impl PartialEq for Disciplina {
+    #[rustfmt::skip]
+    fn eq(&self, other: &Self) -> bool {
+        // Sem `..`: um campo novo precisa ser incluído (ou ignorado de propósito) aqui.
+        let Disciplina {
+            nome,
+            abreviacao: _,
+            sigaa_time,
+            codigo,
+            turma,
+            professores,
+            local,
+            creditos,
+            carga_horaria,
+            detalhes,
+        } = self;
+
+        *nome == other.nome
+            && *sigaa_time == other.sigaa_time
+            && *codigo == other.codigo
+            && *turma == other.turma
+            && *professores == other.professores
+            && *local == other.local
+            && *creditos == other.creditos
+            && *carga_horaria == other.carga_horaria
+            && *detalhes == other.detalhes
+    }
+}
+
+impl Display for Atividade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Atividade::Teorica => write!(f, "teórica"),
+            Atividade::Laboratorio => write!(f, "laboratório"),
+            Atividade::Pratica => write!(f, "prática"),
+            Atividade::Seminario => write!(f, "seminário"),
+        }
+    }
 }
 
 impl Display for Disciplina {
+    /// Ex: `DIM0120 - Cálculo I (turma 01) - CI - 24M12 - Setor II - Ana, Bruno`. Os campos opcionais
+    /// vazios são omitidos.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{} - {} - {}",
-            self.nome,
-            self.abreviacao,
-            generate_horario_display(self)
-        )
+        if let Some(codigo) = &self.codigo {
+            write!(f, "{} - ", codigo)?;
+        }
+
+        write!(f, "{}", self.nome)?;
+
+        if let Some(turma) = &self.turma {
+            write!(f, " (turma {})", turma)?;
+        }
+
+        write!(f, " - {} - {}", self.abreviacao, generate_horario_display(self))?;
+
+        if let Some(local) = &self.local {
+            write!(f, " - {}", local)?;
+        }
+
+        if !self.professores.is_empty() {
+            write!(f, " - {}", self.professores.join(", "))?;
+        }
+
+        Ok(())
     }
 }
 
 impl fmt::Debug for Disciplina {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} -", self.abreviacao)?;
+        write!(f, "{}", self.abreviacao)?;
+
+        match (&self.codigo, &self.turma) {
+            (Some(codigo), Some(turma)) => write!(f, " ({}-T{})", codigo, turma)?,
+            (Some(codigo), None) => write!(f, " ({})", codigo)?,
+            _ => {}
+        }
+
+        write!(f, " -")?;
         for sigaa_time in self.sigaa_time.iter() {
             write!(f, " {}", sigaa_time)?
         }
@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
-use stf::SigaaTime;
+use stf::{ParseError, SigaaTime};
 
 // Representa uma disciplina no sistema.
 ///
 /// Cada disciplina tem um nome, uma abreviação e um conjunto de horários (`SigaaTime`) associados.
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Os dados da turma no SIGAA (código, turma, professores, local, créditos e carga horária) são
+/// opcionais e ficam de fora do JSON quando vazios, então dados antigos continuam válidos.
+#[derive(Clone, Eq, Serialize, Deserialize)]
 pub struct Disciplina {
     /// Nome da disciplina.
     pub nome: String,
@@ -13,6 +15,54 @@ pub struct Disciplina {
     pub abreviacao: String,
     /// Horários associados a esta disciplina.
     pub sigaa_time: BTreeSet<SigaaTime>,
+    /// Código do componente curricular no SIGAA, ex: `DIM0120`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub codigo: Option<String>,
+    /// Número da turma, ex: `01`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turma: Option<String>,
+    /// Professores da turma.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub professores: Vec<String>,
+    /// Sala ou local das aulas.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local: Option<String>,
+    /// Quantidade de créditos do componente.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creditos: Option<u8>,
+    /// Carga horária total do componente, em horas.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub carga_horaria: Option<u16>,
+    /// Local e tipo de atividade próprios de alguns horários, ex: o laboratório da quinta-feira.
+    ///
+    /// Horários sem detalhe usam o `local` da disciplina e `Atividade::Teorica`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub detalhes: Vec<DetalheHorario>,
+}
+
+/// Tipo de atividade realizada num horário.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Atividade {
+    /// Aula expositiva.
+    #[default]
+    Teorica,
+    /// Aula em laboratório.
+    Laboratorio,
+    /// Aula prática (campo, estúdio, quadra...).
+    Pratica,
+    /// Seminário ou apresentação de trabalhos.
+    Seminario,
+}
+
+/// Local e atividade de um horário específico de uma disciplina.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DetalheHorario {
+    pub horario: SigaaTime,
+    /// Local deste horário; `None` usa o local da disciplina.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local: Option<String>,
+    #[serde(default)]
+    pub atividade: Atividade,
 }
 
 /// Erros que podem ocorrer ao lidar com disciplinas.
@@ -23,8 +73,10 @@ pub struct Disciplina {
 pub enum DisciplinaErrors {
     /// Horário já inserido para a disciplina.
     TimeAlreadyInserted,
-    /// String de horário não está no formato correto ([2..7][M|T|N][1..6]).
-    TimeNotFormatted,
+    /// String de horário não está no formato correto ([1..7][M|T|N][1..6], veja `stf::SigaaTime::parse_codes`), com o trecho inválido.
+    TimeNotFormatted(ParseError),
+    /// Horário não pertence à disciplina.
+    TimeNotInDiscipline(SigaaTime),
 }
 
 mod auxiliary;
@@ -0,0 +1,57 @@
+use class::DisciplinaErrors;
+use schedule::{DocumentoError, ScheduleError};
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// Erros que encerram o comando com falha.
+#[derive(Debug)]
+pub enum CliError {
+    /// Falha ao ler ou escrever o arquivo do cronograma.
+    Io(PathBuf, std::io::Error),
+    /// Arquivo do cronograma que não pôde ser lido como `Documento`.
+    Documento(PathBuf, DocumentoError),
+    /// Disciplina informada com horário inválido.
+    Disciplina(DisciplinaErrors),
+    /// Operação recusada pelo cronograma.
+    Schedule(ScheduleError),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Io(caminho, erro) => write!(f, "{}: {erro}", caminho.display()),
+            CliError::Documento(caminho, DocumentoError::InvalidJson(erro)) => write!(f, "{}: {erro}", caminho.display()),
+            CliError::Documento(caminho, DocumentoError::UnsupportedVersion(versao)) => write!(f, "{}: documento na versão {versao}, mais nova do que esta ferramenta", caminho.display()),
+            CliError::Documento(caminho, DocumentoError::UnknownFormat) => write!(f, "{}: formato de cronograma desconhecido", caminho.display()),
+            CliError::Documento(caminho, DocumentoError::Schedule(erro)) => write!(f, "{}: {}", caminho.display(), CliError::Schedule(erro.clone())),
+            CliError::Documento(caminho, DocumentoError::InconsistentGrid(horario)) => write!(f, "{}: a grade salva não bate com as disciplinas em {horario}", caminho.display()),
+            CliError::Disciplina(DisciplinaErrors::TimeNotFormatted(erro)) => write!(f, "{erro}"),
+            CliError::Disciplina(DisciplinaErrors::TimeAlreadyInserted) => write!(f, "horário repetido na disciplina"),
+            CliError::Disciplina(DisciplinaErrors::TimeNotInDiscipline(horario)) => write!(f, "a disciplina não tem aula em {horario}"),
+            CliError::Schedule(ScheduleError::ConflictingDisciplines(conflitos)) => {
+                write!(f, "a disciplina conflita com o cronograma")?;
+                for conflito in conflitos {
+                    write!(f, "\n  {}: {}", conflito.horario, conflito.disciplina.nome)?;
+                }
+                Ok(())
+            }
+            CliError::Schedule(ScheduleError::AbbreviationInUse(abreviacao)) => write!(f, "a abreviação {abreviacao} já é usada por outra disciplina"),
+            CliError::Schedule(ScheduleError::EmptyAbbreviation) => write!(f, "a abreviação não pode ser vazia"),
+            CliError::Schedule(ScheduleError::TimeNotFound(horario)) => write!(f, "o horário {horario} está fora da grade do cronograma"),
+            CliError::Schedule(ScheduleError::DisciplineNotFoundToRemove) => write!(f, "disciplina não encontrada no cronograma"),
+            CliError::Schedule(erro) => write!(f, "{erro:?}"),
+        }
+    }
+}
+
+impl From<DisciplinaErrors> for CliError {
+    fn from(erro: DisciplinaErrors) -> Self {
+        CliError::Disciplina(erro)
+    }
+}
+
+impl From<ScheduleError> for CliError {
+    fn from(erro: ScheduleError) -> Self {
+        CliError::Schedule(erro)
+    }
+}
@@ -0,0 +1,167 @@
+use schedule::Schedule;
+use stf::SigaaTime;
+
+/// Caracteres usados para desenhar a tabela.
+struct Bordas {
+    horizontal: char,
+    vertical: char,
+    /// Cantos e cruzamentos da linha de cima, do meio e de baixo (esquerda, meio, direita).
+    topo: [char; 3],
+    meio: [char; 3],
+    base: [char; 3],
+}
+
+const ASCII: Bordas = Bordas { horizontal: '-', vertical: '|', topo: ['+', '+', '+'], meio: ['+', '+', '+'], base: ['+', '+', '+'] };
+
+const UNICODE: Bordas = Bordas { horizontal: '─', vertical: '│', topo: ['┌', '┬', '┐'], meio: ['├', '┼', '┤'], base: ['└', '┴', '┘'] };
+
+/// Desenha a grade do cronograma, com os turnos nas linhas e os dias nas colunas, segundo o formato dela.
+///
+/// Cada célula mostra a abreviação da disciplina; horários sobrepostos mostram todas, separadas por `/`.
+pub fn desenhar(schedule: &Schedule, unicode: bool) -> String {
+    let bordas = match unicode {
+        true => &UNICODE,
+        false => &ASCII,
+    };
+
+    let formato = schedule.format();
+    let mut linhas: Vec<Vec<String>> = vec![std::iter::once(String::new())
+        .chain(
+            formato
+                .dias()
+                .iter()
+                .map(|dia| dia.abreviacao().to_string()),
+        )
+        .collect()];
+
+    for &turno in formato.turnos() {
+        let mut linha = vec![turno.to_string()];
+        for &dia in formato.dias() {
+            let abreviacoes: Vec<&str> = schedule
+                .get(&SigaaTime::new(dia, turno))
+                .into_iter()
+                .flat_map(|unity| unity.disciplinas())
+                .map(|disciplina| disciplina.abreviacao.as_str())
+                .collect();
+            linha.push(abreviacoes.join("/"));
+        }
+        linhas.push(linha);
+    }
+
+    let larguras: Vec<usize> = (0..=formato.dias().len())
+        .map(|coluna| {
+            linhas
+                .iter()
+                .map(|linha| linha[coluna].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut tabela = separador(&larguras, bordas.topo, bordas.horizontal);
+    for (indice, linha) in linhas.iter().enumerate() {
+        tabela.push(bordas.vertical);
+        for (celula, largura) in linha.iter().zip(&larguras) {
+            let espacos = largura - celula.chars().count();
+            tabela.push_str(&format!(" {celula}{} {}", " ".repeat(espacos), bordas.vertical));
+        }
+        tabela.push('\n');
+
+        let cantos = match indice + 1 == linhas.len() {
+            true => bordas.base,
+            false => bordas.meio,
+        };
+        tabela.push_str(&separador(&larguras, cantos, bordas.horizontal));
+    }
+
+    tabela
+}
+
+/// Uma linha por disciplina do cronograma, com a abreviação usada na grade e os dados da turma.
+pub fn legenda(schedule: &Schedule) -> String {
+    schedule
+        .disciplines()
+        .iter()
+        .map(|disciplina| format!("{}: {}\n", disciplina.abreviacao, disciplina))
+        .collect()
+}
+
+fn separador(larguras: &[usize], [esquerda, meio, direita]: [char; 3], horizontal: char) -> String {
+    let colunas: Vec<String> = larguras
+        .iter()
+        .map(|largura| horizontal.to_string().repeat(largura + 2))
+        .collect();
+
+    format!("{esquerda}{}{direita}\n", colunas.join(&meio.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use class::Disciplina;
+    use schedule::{FormatoGrade, OverlapPolicy};
+    use stf::{Dia, HorarioDiurno, Turno};
+
+    #[test]
+    fn should_draw_an_ascii_grid() {
+        let mut schedule = Schedule::new();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo", "24M12").unwrap())
+            .unwrap();
+
+        let grade = desenhar(&schedule, false);
+        let linhas: Vec<&str> = grade.lines().collect();
+
+        assert_eq!(linhas.len(), 19);
+        assert_eq!(linhas[0], "+-----+-----+-----+-----+-----+-----+-----+");
+        assert_eq!(linhas[1], "|     | Seg | Ter | Qua | Qui | Sex | Sáb |");
+        assert_eq!(linhas[3], "| M12 | C   |     | C   |     |     |     |");
+        assert_eq!(linhas[17], "| N34 |     |     |     |     |     |     |");
+    }
+
+    #[test]
+    fn should_draw_only_the_days_and_turnos_of_the_format() {
+        let formato = FormatoGrade::new(&[Dia::Sabado, Dia::Domingo], &[Turno::Manhã(HorarioDiurno::Primeiro), Turno::Tarde(HorarioDiurno::Primeiro)]);
+        let mut schedule = Schedule::with_format(formato);
+        schedule
+            .insert(Disciplina::new_stringify("Plantão", "17T12").unwrap())
+            .unwrap();
+
+        let grade = desenhar(&schedule, false);
+        let linhas: Vec<&str> = grade.lines().collect();
+
+        assert_eq!(linhas.len(), 7);
+        assert_eq!(linhas[1], "|     | Dom | Sáb |");
+        assert_eq!(linhas[5], "| T12 | P   | P   |");
+    }
+
+    #[test]
+    fn should_list_each_discipline_once_in_the_legend() {
+        let mut schedule = Schedule::new();
+        let calculo = Disciplina { codigo: Some("MAT0311".to_string()), ..Disciplina::new_stringify("Cálculo", "24M12").unwrap() };
+        schedule.insert(calculo).unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Física", "3T12").unwrap())
+            .unwrap();
+
+        assert_eq!(legenda(&schedule), "C: MAT0311 - Cálculo - C - 24M12\nF: Física - F - 3T12\n");
+    }
+
+    #[test]
+    fn should_draw_overlaps_with_unicode_borders() {
+        let mut schedule = Schedule::with_policy(OverlapPolicy::Allow);
+        schedule
+            .insert(Disciplina::new_stringify("Física", "2T12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Laboratório Física", "2T12").unwrap())
+            .unwrap();
+
+        let grade = desenhar(&schedule, true);
+        let linhas: Vec<&str> = grade.lines().collect();
+
+        assert!(linhas[0].starts_with("┌─────┬"));
+        assert!(linhas[18].ends_with("┘"));
+        assert!(linhas[9].starts_with("│ T12 │ F/LF │"));
+    }
+}
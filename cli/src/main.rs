@@ -0,0 +1,319 @@
+//! `sigaa-schedule`: monta e inspeciona cronogramas do SIGAA pelo terminal.
+//!
+//! O cronograma fica salvo em um `Documento` JSON (por padrão `schedule.json`), criado na primeira
+//! inserção. Arquivos de versões anteriores são migrados ao serem salvos de novo. Exemplo:
+//!
+//! ```text
+//! sigaa-schedule add "Cálculo I" 24M12
+//! sigaa-schedule check "Física I" 2M12
+//! sigaa-schedule pin "Cálculo I" CALC
+//! sigaa-schedule undo
+//! sigaa-schedule show --unicode
+//! ```
+
+use clap::{Args, Parser, Subcommand};
+use class::Disciplina;
+use erro::CliError;
+use schedule::{Conflict, DiasLetivos, Documento, Edicao, FormatoGrade, Schedule};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use stf::{Dia, Turno};
+
+mod erro;
+mod grade;
+
+#[derive(Parser)]
+#[command(name = "sigaa-schedule", version, about = "Monta e inspeciona cronogramas do SIGAA")]
+struct Cli {
+    /// Arquivo do cronograma.
+    #[arg(short, long, global = true, default_value = "schedule.json")]
+    file: PathBuf,
+
+    #[command(subcommand)]
+    comando: Comando,
+}
+
+#[derive(Subcommand)]
+enum Comando {
+    /// Adiciona uma disciplina ao cronograma.
+    Add {
+        /// Nome da disciplina.
+        nome: String,
+        /// Horário no formato do SIGAA, ex: "24M12 6T34".
+        horario: String,
+        #[command(flatten)]
+        turma: DadosTurma,
+    },
+    /// Remove uma disciplina do cronograma.
+    Remove {
+        /// Nome da disciplina.
+        nome: String,
+        /// Horário no formato do SIGAA.
+        horario: String,
+    },
+    /// Fixa a abreviação de uma disciplina na grade.
+    Pin {
+        /// Nome da disciplina.
+        nome: String,
+        /// Abreviação a ser usada.
+        abreviacao: String,
+    },
+    /// Desfaz a última inserção ou remoção.
+    Undo,
+    /// Refaz a última edição desfeita.
+    Redo,
+    /// Mostra a grade do cronograma.
+    Show {
+        /// Desenha a tabela com caracteres Unicode em vez de ASCII.
+        #[arg(long)]
+        unicode: bool,
+    },
+    /// Mostra a carga horária de cada disciplina e do cronograma.
+    ///
+    /// Usa o semestre salvo no documento, se houver; senão, o número de semanas informado.
+    Workload {
+        /// Semanas do período letivo.
+        #[arg(long, default_value_t = 18)]
+        semanas: u32,
+    },
+    /// Lista conflitos: de uma disciplina candidata, ou as sobreposições já existentes.
+    Check {
+        /// Nome da disciplina candidata.
+        nome: Option<String>,
+        /// Horário da disciplina candidata.
+        #[arg(requires = "nome")]
+        horario: Option<String>,
+    },
+    /// Lista os blocos de horários livres, em comum com os cronogramas de outros arquivos.
+    Free {
+        /// Arquivos de outros cronogramas que também precisam estar livres.
+        #[arg(long = "com")]
+        outros: Vec<PathBuf>,
+        /// Quantidade mínima de horários seguidos em cada bloco.
+        #[arg(long, default_value_t = 1)]
+        minimo: usize,
+    },
+    /// Define os dias e turnos da grade, ex: `format 1234567` para incluir o domingo.
+    Format {
+        /// Códigos dos dias da grade (1 é domingo, 7 é sábado).
+        #[arg(value_parser = parse_dias)]
+        dias: Dias,
+        /// Turno da grade, ex: M12 (pode ser repetido; sem nenhum, usa todos).
+        #[arg(long = "turno", value_parser = parse_turno)]
+        turnos: Vec<Turno>,
+    },
+    /// Mostra o que muda do cronograma do arquivo para o de outro arquivo.
+    Diff {
+        /// Arquivo com a outra versão do cronograma.
+        outro: PathBuf,
+    },
+}
+
+/// Dias da grade, lidos de um único argumento como `234567`.
+#[derive(Clone)]
+struct Dias(Vec<Dia>);
+
+/// Dados opcionais da turma no SIGAA.
+#[derive(Args)]
+struct DadosTurma {
+    /// Código do componente, ex: DIM0120.
+    #[arg(long)]
+    codigo: Option<String>,
+    /// Número da turma.
+    #[arg(long)]
+    turma: Option<String>,
+    /// Professor da turma (pode ser repetido).
+    #[arg(long = "professor")]
+    professores: Vec<String>,
+    /// Sala ou local das aulas.
+    #[arg(long)]
+    local: Option<String>,
+    /// Quantidade de créditos.
+    #[arg(long)]
+    creditos: Option<u8>,
+    /// Carga horária total, em horas.
+    #[arg(long)]
+    carga_horaria: Option<u16>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match executar(cli) {
+        Ok(codigo) => codigo,
+        Err(erro) => {
+            eprintln!("erro: {erro}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn executar(cli: Cli) -> Result<ExitCode, CliError> {
+    let mut documento = carregar(&cli.file)?;
+    let mut schedule = documento
+        .to_schedule()
+        .map_err(|erro| CliError::Documento(cli.file.clone(), erro))?;
+
+    match cli.comando {
+        Comando::Add { nome, horario, turma } => {
+            let disciplina = Disciplina { codigo: turma.codigo, turma: turma.turma, professores: turma.professores, local: turma.local, creditos: turma.creditos, carga_horaria: turma.carga_horaria, ..Disciplina::new_stringify(&nome, &horario)? };
+            let avisos = documento
+                .historico
+                .execute(&mut schedule, Edicao::Insert(disciplina))?;
+            imprimir_conflitos(&avisos);
+            salvar(&cli.file, &mut documento, &schedule)?;
+        }
+        Comando::Remove { nome, horario } => {
+            let disciplina = salva(&schedule, Disciplina::new_stringify(&nome, &horario)?);
+            documento
+                .historico
+                .execute(&mut schedule, Edicao::Remove(disciplina))?;
+            salvar(&cli.file, &mut documento, &schedule)?;
+        }
+        Comando::Pin { nome, abreviacao } => {
+            schedule.pin_abbreviation(&nome, &abreviacao)?;
+            salvar(&cli.file, &mut documento, &schedule)?;
+        }
+        Comando::Undo => match documento.historico.undo(&mut schedule)? {
+            Some(edicao) => {
+                println!("desfeito: {}", descrever(&edicao));
+                salvar(&cli.file, &mut documento, &schedule)?;
+            }
+            None => println!("nada para desfazer"),
+        },
+        Comando::Redo => match documento.historico.redo(&mut schedule)? {
+            Some(edicao) => {
+                println!("refeito: {}", descrever(&edicao));
+                salvar(&cli.file, &mut documento, &schedule)?;
+            }
+            None => println!("nada para refazer"),
+        },
+        Comando::Show { unicode } => {
+            print!("{}", grade::desenhar(&schedule, unicode));
+            print!("{}", grade::legenda(&schedule));
+        }
+        Comando::Workload { semanas } => {
+            let dias = match &documento.semestre {
+                Some(semestre) => DiasLetivos::from(semestre),
+                None => DiasLetivos::semanas(semanas),
+            };
+            let carga = schedule.workload(&documento.configuracoes.tabela, &dias);
+
+            for disciplina in &carga.disciplinas {
+                println!("{}: {} min/semana, {:.1} h", disciplina.disciplina.nome, disciplina.minutos_semanais, disciplina.horas_periodo());
+            }
+            println!("total: {} min/semana, {:.1} h", carga.minutos_semanais, carga.horas_periodo());
+        }
+        Comando::Check { nome, horario } => {
+            let conflitos = match (nome, horario) {
+                (Some(nome), Some(horario)) => schedule.conflicts(&Disciplina::new_stringify(&nome, &horario)?),
+                _ => sobreposicoes(&schedule),
+            };
+
+            if conflitos.is_empty() {
+                println!("nenhum conflito");
+                return Ok(ExitCode::SUCCESS);
+            }
+
+            imprimir_conflitos(&conflitos);
+            return Ok(ExitCode::FAILURE);
+        }
+        Comando::Free { outros, minimo } => {
+            let mut schedules = vec![schedule];
+            for outro in outros {
+                schedules.push(
+                    carregar(&outro)?
+                        .to_schedule()
+                        .map_err(|erro| CliError::Documento(outro, erro))?,
+                );
+            }
+
+            for bloco in Schedule::common_free_blocks(&schedules, minimo) {
+                println!("{bloco}");
+            }
+        }
+        Comando::Format { dias: Dias(dias), turnos } => {
+            let turnos = match turnos.is_empty() {
+                true => Turno::TODOS.to_vec(),
+                false => turnos,
+            };
+
+            schedule.set_format(FormatoGrade::new(&dias, &turnos))?;
+            salvar(&cli.file, &mut documento, &schedule)?;
+        }
+        Comando::Diff { outro } => {
+            let outro_schedule = carregar(&outro)?
+                .to_schedule()
+                .map_err(|erro| CliError::Documento(outro.clone(), erro))?;
+
+            print!("{}", schedule.diff(&outro_schedule));
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Lê o documento do arquivo. Um arquivo inexistente é tratado como um cronograma vazio.
+fn carregar(caminho: &Path) -> Result<Documento, CliError> {
+    match std::fs::read_to_string(caminho) {
+        Ok(conteudo) => Documento::from_json(&conteudo).map_err(|erro| CliError::Documento(caminho.to_path_buf(), erro)),
+        Err(erro) if erro.kind() == std::io::ErrorKind::NotFound => Ok(Documento::new(&Schedule::new())),
+        Err(erro) => Err(CliError::Io(caminho.to_path_buf(), erro)),
+    }
+}
+
+fn salvar(caminho: &Path, documento: &mut Documento, schedule: &Schedule) -> Result<(), CliError> {
+    documento.set_schedule(schedule);
+    std::fs::write(caminho, documento.to_json()).map_err(|erro| CliError::Io(caminho.to_path_buf(), erro))
+}
+
+/// Disciplina do cronograma com o mesmo nome e horário, com os dados de turma que foram salvos.
+fn salva(schedule: &Schedule, disciplina: Disciplina) -> Disciplina {
+    schedule
+        .conflicts(&disciplina)
+        .into_iter()
+        .map(|conflito| conflito.disciplina)
+        .find(|salva| salva.nome == disciplina.nome && salva.sigaa_time == disciplina.sigaa_time)
+        .unwrap_or(disciplina)
+}
+
+/// Lê os dias de um código como `234567`.
+fn parse_dias(codigo: &str) -> Result<Dias, String> {
+    codigo
+        .chars()
+        .map(|c| Dia::try_from(c.to_string().as_str()).map_err(|_| format!("'{c}' não é um dia válido (1 a 7)")))
+        .collect::<Result<_, _>>()
+        .map(Dias)
+}
+
+fn parse_turno(turno: &str) -> Result<Turno, String> {
+    Turno::try_from(turno).map_err(|_| format!("'{turno}' não é um turno válido (ex: M12, T34, N12)"))
+}
+
+fn descrever(edicao: &Edicao) -> String {
+    match edicao {
+        Edicao::Insert(disciplina) => format!("adicionar {}", disciplina.nome),
+        Edicao::Remove(disciplina) => format!("remover {}", disciplina.nome),
+        Edicao::Rename { disciplina, nome } => format!("renomear {} para {}", disciplina.nome, nome),
+        Edicao::Move { disciplina, .. } => format!("mover {}", disciplina.nome),
+    }
+}
+
+/// Conflitos entre as disciplinas que dividem algum horário do cronograma.
+fn sobreposicoes(schedule: &Schedule) -> Vec<Conflict> {
+    schedule
+        .overlaps()
+        .into_iter()
+        .flat_map(|unidade| {
+            unidade
+                .disciplinas()
+                .map(|disciplina| Conflict { horario: unidade.horario, disciplina: disciplina.clone() })
+        })
+        .collect()
+}
+
+fn imprimir_conflitos(conflitos: &[Conflict]) {
+    for conflito in conflitos {
+        println!("conflito em {}: {}", conflito.horario, conflito.disciplina.nome);
+    }
+}
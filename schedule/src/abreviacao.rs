@@ -0,0 +1,136 @@
+use super::{Schedule, ScheduleError};
+use class::Disciplina;
+use std::collections::{BTreeMap, BTreeSet};
+
+impl Schedule {
+    /// Fixa a abreviação de uma disciplina, aplicando-a às que já estão no cronograma e às inseridas depois.
+    ///
+    /// Retorna `ScheduleError::EmptyAbbreviation` se a abreviação for vazia (ou só espaços) e
+    /// `ScheduleError::AbbreviationInUse` se outra disciplina já usar essa abreviação. A troca não é
+    /// registrada no `Historico`.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::Schedule;
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap()).unwrap();
+    /// schedule.pin_abbreviation("Cálculo I", "CALC1").unwrap();
+    ///
+    /// assert_eq!(schedule.get_from_str("2M12").unwrap().disciplina.as_ref().unwrap().abreviacao, "CALC1");
+    /// ```
+    pub fn pin_abbreviation(&mut self, nome: &str, abreviacao: &str) -> Result<(), ScheduleError> {
+        if abreviacao.trim().is_empty() {
+            return Err(ScheduleError::EmptyAbbreviation);
+        }
+
+        if self
+            .abbreviations_in_use(nome)
+            .contains(&abreviacao.to_string())
+        {
+            return Err(ScheduleError::AbbreviationInUse(abreviacao.to_string()));
+        }
+
+        self.fixadas
+            .insert(nome.to_string(), abreviacao.to_string());
+        self.relabel(nome, abreviacao);
+
+        Ok(())
+    }
+
+    /// Deixa de fixar a abreviação de uma disciplina, devolvendo a que estava fixada.
+    ///
+    /// As disciplinas já inseridas com esse nome voltam à abreviação gerada pelo nome, ou à primeira livre
+    /// se ela já for usada por outra disciplina.
+    pub fn unpin_abbreviation(&mut self, nome: &str) -> Option<String> {
+        let fixada = self.fixadas.remove(nome)?;
+
+        let mut disciplina = Disciplina::new(nome, BTreeSet::new());
+        self.free_abbreviation(&mut disciplina);
+        self.relabel(nome, &disciplina.abreviacao);
+
+        Some(fixada)
+    }
+
+    /// Abreviações fixadas, por nome de disciplina.
+    pub fn pinned_abbreviations(&self) -> &BTreeMap<String, String> {
+        &self.fixadas
+    }
+
+    /// Escolhe a abreviação da disciplina antes de inseri-la.
+    ///
+    /// A abreviação fixada tem prioridade. Sem ela, a disciplina mantém a própria abreviação ou a de outra
+    /// disciplina com o mesmo nome; se ela já for usada por outro nome, passa para a primeira das
+    /// `Disciplina::abreviacoes_candidatas` livre e, em último caso, ganha um número no fim.
+    pub(crate) fn allocate_abbreviation(&self, disciplina: &mut Disciplina) {
+        if let Some(fixada) = self.fixadas.get(&disciplina.nome) {
+            disciplina.abreviacao = fixada.clone();
+            return;
+        }
+
+        let mesmo_nome = self
+            .disciplines()
+            .into_iter()
+            .find(|outra| outra.nome == disciplina.nome);
+
+        if let Some(outra) = mesmo_nome {
+            disciplina.abreviacao = outra.abreviacao.clone();
+            return;
+        }
+
+        self.free_abbreviation(disciplina);
+    }
+
+    /// Troca a abreviação da disciplina, se outro nome já a usar, pela primeira candidata livre.
+    fn free_abbreviation(&self, disciplina: &mut Disciplina) {
+        let em_uso = self.abbreviations_in_use(&disciplina.nome);
+        if !em_uso.contains(&disciplina.abreviacao) {
+            return;
+        }
+
+        let livre = disciplina
+            .abreviacoes_candidatas()
+            .into_iter()
+            .find(|candidata| !candidata.is_empty() && !em_uso.contains(candidata));
+
+        disciplina.abreviacao = match livre {
+            Some(livre) => livre,
+            None => (2..)
+                .map(|numero| format!("{}{}", disciplina.abreviacao, numero))
+                .find(|candidata| !em_uso.contains(candidata))
+                .unwrap_or_default(),
+        };
+    }
+
+    /// Aplica a abreviação a todas as disciplinas inseridas com esse nome.
+    fn relabel(&mut self, nome: &str, abreviacao: &str) {
+        for unity in self.grade.iter_mut().flatten() {
+            for disciplina in unity
+                .disciplina
+                .iter_mut()
+                .chain(unity.sobrepostas.iter_mut())
+            {
+                if disciplina.nome == nome {
+                    disciplina.abreviacao = abreviacao.to_string();
+                }
+            }
+        }
+    }
+
+    /// Abreviações usadas por disciplinas com outro nome, inseridas ou fixadas.
+    fn abbreviations_in_use(&self, nome: &str) -> Vec<String> {
+        self.disciplines()
+            .into_iter()
+            .filter(|disciplina| disciplina.nome != nome)
+            .map(|disciplina| disciplina.abreviacao.clone())
+            .chain(
+                self.fixadas
+                    .iter()
+                    .filter(|(fixado, _)| fixado.as_str() != nome)
+                    .map(|(_, abreviacao)| abreviacao.clone()),
+            )
+            .collect()
+    }
+}
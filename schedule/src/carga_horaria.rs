@@ -0,0 +1,133 @@
+use super::semestre::to_weekday;
+use super::{CargaDisciplina, CargaHoraria, DiasLetivos, Schedule, Semestre};
+use chrono::Datelike;
+use std::collections::BTreeSet;
+use stf::{Dia, SigaaSlot, TabelaHorarios};
+
+/// Semanas de um semestre padrão.
+const SEMANAS_SEMESTRE: u32 = 18;
+
+impl DiasLetivos {
+    /// Período com o mesmo número de semanas para todos os dias.
+    pub fn semanas(semanas: u32) -> DiasLetivos {
+        DiasLetivos { aulas: [semanas; 7] }
+    }
+
+    /// Desconta um dia de aula para cada feriado informado (ex: `[Dia::Segunda, Dia::Segunda]` tira duas segundas).
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use schedule::DiasLetivos;
+    /// use stf::Dia;
+    ///
+    /// let dias = DiasLetivos::semanas(15).sem_feriados(&[Dia::Segunda, Dia::Segunda]);
+    /// assert_eq!(dias.aulas(Dia::Segunda), 13);
+    /// assert_eq!(dias.aulas(Dia::Terça), 15);
+    /// ```
+    pub fn sem_feriados(mut self, feriados: &[Dia]) -> DiasLetivos {
+        for dia in feriados {
+            let aulas = &mut self.aulas[usize::from(*dia)];
+            *aulas = aulas.saturating_sub(1);
+        }
+
+        self
+    }
+
+    /// Número de aulas no dia da semana.
+    pub fn aulas(&self, dia: Dia) -> u32 {
+        self.aulas[usize::from(dia)]
+    }
+
+    /// Tempo total de aula dos períodos informados ao longo de todo o período letivo, em minutos.
+    pub fn minutos(&self, tabela: &TabelaHorarios, slots: &BTreeSet<SigaaSlot>) -> u32 {
+        slots
+            .iter()
+            .map(|slot| slot.duracao_minutos(tabela) as u32 * self.aulas(slot.dia))
+            .sum()
+    }
+}
+
+impl Default for DiasLetivos {
+    fn default() -> Self {
+        DiasLetivos::semanas(SEMANAS_SEMESTRE)
+    }
+}
+
+impl From<&Semestre> for DiasLetivos {
+    /// Conta as datas do semestre que caem em cada dia da semana, sem os feriados.
+    fn from(semestre: &Semestre) -> Self {
+        let mut aulas = [0; 7];
+
+        for (dia, aulas) in Dia::TODOS.into_iter().zip(aulas.iter_mut()) {
+            *aulas = semestre
+                .inicio
+                .iter_days()
+                .take_while(|data| *data <= semestre.fim)
+                .filter(|data| data.weekday() == to_weekday(dia) && !semestre.feriados.contains(data))
+                .count() as u32;
+        }
+
+        DiasLetivos { aulas }
+    }
+}
+
+impl CargaHoraria {
+    /// Horas de aula no período letivo inteiro.
+    pub fn horas_periodo(&self) -> f64 {
+        self.minutos_periodo as f64 / 60.0
+    }
+}
+
+impl CargaDisciplina {
+    /// Horas de aula da disciplina no período letivo inteiro.
+    pub fn horas_periodo(&self) -> f64 {
+        self.minutos_periodo as f64 / 60.0
+    }
+}
+
+impl Schedule {
+    /// Tempo em aula por semana, em minutos. Horários sobrepostos contam uma vez só.
+    pub fn weekly_minutes(&self, tabela: &TabelaHorarios) -> u32 {
+        tabela.duracao_total_minutos(&self.slots())
+    }
+
+    /// Carga horária do cronograma e de cada disciplina no período letivo.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::{DiasLetivos, Schedule};
+    /// use stf::TabelaHorarios;
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap()).unwrap();
+    /// schedule.insert(Disciplina::new_stringify("Física I", "3N34").unwrap()).unwrap();
+    ///
+    /// let carga = schedule.workload(&TabelaHorarios::default(), &DiasLetivos::default());
+    ///
+    /// assert_eq!(carga.minutos_semanais, 300);
+    /// assert_eq!(carga.horas_periodo(), 90.0);
+    /// assert_eq!(carga.disciplinas[0].horas_periodo(), 60.0);
+    /// ```
+    pub fn workload(&self, tabela: &TabelaHorarios, dias: &DiasLetivos) -> CargaHoraria {
+        let disciplinas = self
+            .disciplines()
+            .into_iter()
+            .map(|disciplina| CargaDisciplina { disciplina: disciplina.clone(), minutos_semanais: disciplina.duracao_semanal_minutos(tabela), minutos_periodo: dias.minutos(tabela, &disciplina.slots()) })
+            .collect();
+
+        let slots = self.slots();
+
+        CargaHoraria { minutos_semanais: tabela.duracao_total_minutos(&slots), minutos_periodo: dias.minutos(tabela, &slots), disciplinas }
+    }
+
+    /// Todos os períodos ocupados por alguma disciplina.
+    fn slots(&self) -> BTreeSet<SigaaSlot> {
+        self.disciplines()
+            .into_iter()
+            .flat_map(|disciplina| disciplina.slots())
+            .collect()
+    }
+}
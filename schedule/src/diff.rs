@@ -0,0 +1,243 @@
+use super::{Alteracao, Merge, MergeConflict, MudancaHorario, Schedule, ScheduleDiff};
+use class::Disciplina;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use stf::SigaaTime;
+
+impl Schedule {
+    /// Compara este cronograma (versão antiga) com `depois` (versão nova).
+    ///
+    /// As disciplinas são casadas pelo nome: se sobrar exatamente uma de cada lado, ela aparece como
+    /// movida (outros horários) ou alterada (mesmos horários, outros dados), o que cobre a troca de turma.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::Schedule;
+    ///
+    /// let mut antes = Schedule::new();
+    /// antes.insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap()).unwrap();
+    ///
+    /// let mut depois = Schedule::new();
+    /// depois.insert(Disciplina::new_stringify("Cálculo I", "35M12").unwrap()).unwrap();
+    ///
+    /// let diff = antes.diff(&depois);
+    /// assert_eq!(diff.movidas.len(), 1);
+    /// assert_eq!(diff.horarios.len(), 4);
+    /// assert_eq!(diff.to_string().lines().next(), Some("~ Cálculo I: 2M12 4M12 -> 3M12 5M12"));
+    /// ```
+    pub fn diff(&self, depois: &Schedule) -> ScheduleDiff {
+        let (antigas, novas) = (por_nome(self), por_nome(depois));
+        let mut diff = ScheduleDiff::default();
+
+        for nome in nomes(&[&antigas, &novas]) {
+            let antes = exclusivas(antigas.get(nome), novas.get(nome));
+            let depois = exclusivas(novas.get(nome), antigas.get(nome));
+
+            match (antes.as_slice(), depois.as_slice()) {
+                ([antes], [depois]) if antes.sigaa_time != depois.sigaa_time => diff
+                    .movidas
+                    .push(Alteracao { antes: (*antes).clone(), depois: (*depois).clone() }),
+                ([antes], [depois]) => diff
+                    .alteradas
+                    .push(Alteracao { antes: (*antes).clone(), depois: (*depois).clone() }),
+                _ => {
+                    diff.removidas.extend(antes.into_iter().cloned());
+                    diff.adicionadas
+                        .extend(depois.into_iter().cloned());
+                }
+            }
+        }
+
+        // As grades podem ter formatos diferentes: um horário que só existe de um lado conta como vazio do outro.
+        let mut horarios: Vec<SigaaTime> = self
+            .grade
+            .iter()
+            .flatten()
+            .map(|unity| unity.horario)
+            .collect();
+        horarios.extend(
+            depois
+                .grade
+                .iter()
+                .flatten()
+                .map(|unity| unity.horario)
+                .filter(|horario| !self.formato.contains(horario)),
+        );
+        horarios.sort_by_key(|horario| (usize::from(horario.turno), usize::from(horario.dia)));
+
+        for horario in horarios {
+            let ocupantes_antes: Vec<&Disciplina> = self
+                .get(&horario)
+                .into_iter()
+                .flat_map(|unity| unity.disciplinas())
+                .collect();
+            let ocupantes_depois: Vec<&Disciplina> = depois
+                .get(&horario)
+                .into_iter()
+                .flat_map(|unity| unity.disciplinas())
+                .collect();
+
+            if !mesmas(&ocupantes_antes, &ocupantes_depois) {
+                diff.horarios
+                    .push(MudancaHorario { horario, antes: ocupantes_antes.into_iter().cloned().collect(), depois: ocupantes_depois.into_iter().cloned().collect() });
+            }
+        }
+
+        diff
+    }
+
+    /// Junta duas cópias (`nossa` e `deles`) editadas a partir de um mesmo cronograma `base`.
+    ///
+    /// Para cada disciplina, vale a alteração feita por apenas um dos lados. Se os dois lados alteraram a
+    /// mesma disciplina de formas diferentes, a versão `nossa` é mantida e o caso vira um
+    /// `MergeConflict::BothChanged`. Disciplinas que não cabem na grade resultante ficam de fora e são
+    /// informadas como `MergeConflict::SlotTaken`, qualquer que seja a política de sobreposição. A política, a grade e as abreviações fixadas vêm de `nossa`.
+    pub fn merge(base: &Schedule, nossa: &Schedule, deles: &Schedule) -> Merge {
+        let (originais, nossas, delas) = (por_nome(base), por_nome(nossa), por_nome(deles));
+        let mut conflitos = Vec::new();
+        let mut escolhidas: Vec<&Disciplina> = Vec::new();
+        let mut vindas_deles: Vec<&Disciplina> = Vec::new();
+
+        for nome in nomes(&[&originais, &nossas, &delas]) {
+            let original = originais.get(nome).cloned().unwrap_or_default();
+            let nossa = nossas.get(nome).cloned().unwrap_or_default();
+            let dela = delas.get(nome).cloned().unwrap_or_default();
+
+            match (mesmas(&nossa, &dela), mesmas(&nossa, &original), mesmas(&dela, &original)) {
+                (true, _, _) | (false, false, true) => escolhidas.extend(nossa),
+                (false, true, _) => vindas_deles.extend(dela),
+                (false, false, false) => {
+                    conflitos.push(MergeConflict::BothChanged {
+                        nome: nome.to_string(),
+                        nossa: nossa
+                            .iter()
+                            .map(|disciplina| (*disciplina).clone())
+                            .collect(),
+                        deles: dela
+                            .iter()
+                            .map(|disciplina| (*disciplina).clone())
+                            .collect(),
+                    });
+                    escolhidas.extend(nossa);
+                }
+            }
+        }
+
+        let mut schedule = Schedule::with_format(nossa.format().clone());
+        schedule.set_policy(nossa.policy());
+        schedule.fixadas = nossa.fixadas.clone();
+
+        // As nossas entram primeiro: em disputa por horário, quem fica de fora é a alteração deles.
+        // A política de `nossa` não vale aqui: aceitar a sobreposição esconderia a disputa de quem fez a junção.
+        for disciplina in escolhidas.into_iter().chain(vindas_deles) {
+            let conflitos_horario = schedule.conflicts(disciplina);
+
+            match conflitos_horario.is_empty() {
+                true if schedule.insert(disciplina.clone()).is_ok() => {}
+                true => conflitos.push(MergeConflict::SlotTaken { disciplina: disciplina.clone(), conflitos: vec![] }),
+                false => conflitos.push(MergeConflict::SlotTaken { disciplina: disciplina.clone(), conflitos: conflitos_horario }),
+            }
+        }
+
+        Merge { schedule, conflitos }
+    }
+}
+
+impl ScheduleDiff {
+    /// Indica se as duas versões comparadas são iguais.
+    pub fn is_empty(&self) -> bool {
+        self.adicionadas.is_empty() && self.removidas.is_empty() && self.movidas.is_empty() && self.alteradas.is_empty() && self.horarios.is_empty()
+    }
+}
+
+impl Display for ScheduleDiff {
+    /// Uma linha por alteração: `+` adicionada, `-` removida, `~` movida, `*` alterada, seguidas dos
+    /// horários que mudaram de ocupante.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "Sem alterações");
+        }
+
+        for disciplina in &self.adicionadas {
+            writeln!(f, "+ {} ({})", disciplina.nome, horarios(disciplina))?;
+        }
+
+        for disciplina in &self.removidas {
+            writeln!(f, "- {} ({})", disciplina.nome, horarios(disciplina))?;
+        }
+
+        for Alteracao { antes, depois } in &self.movidas {
+            writeln!(f, "~ {}: {} -> {}", antes.nome, horarios(antes), horarios(depois))?;
+        }
+
+        for Alteracao { depois, .. } in &self.alteradas {
+            writeln!(f, "* {} ({}): dados alterados", depois.nome, horarios(depois))?;
+        }
+
+        for MudancaHorario { horario, antes, depois } in &self.horarios {
+            writeln!(f, "  {}: {} -> {}", horario, ocupantes(antes), ocupantes(depois))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Disciplinas distintas do cronograma, agrupadas pelo nome.
+fn por_nome(schedule: &Schedule) -> BTreeMap<&str, Vec<&Disciplina>> {
+    let mut grupos: BTreeMap<&str, Vec<&Disciplina>> = BTreeMap::new();
+
+    for disciplina in schedule.disciplines() {
+        grupos
+            .entry(disciplina.nome.as_str())
+            .or_default()
+            .push(disciplina);
+    }
+
+    grupos
+}
+
+/// Todos os nomes que aparecem em algum dos agrupamentos, em ordem alfabética.
+fn nomes<'a>(grupos: &[&BTreeMap<&'a str, Vec<&Disciplina>>]) -> BTreeSet<&'a str> {
+    grupos
+        .iter()
+        .flat_map(|grupo| grupo.keys().copied())
+        .collect()
+}
+
+/// Disciplinas de `de` que não aparecem em `exceto`.
+fn exclusivas<'a>(de: Option<&Vec<&'a Disciplina>>, exceto: Option<&Vec<&Disciplina>>) -> Vec<&'a Disciplina> {
+    let exceto = exceto.map(Vec::as_slice).unwrap_or_default();
+
+    de.into_iter()
+        .flatten()
+        .copied()
+        .filter(|disciplina| !exceto.contains(disciplina))
+        .collect()
+}
+
+/// Compara duas listas de disciplinas sem levar a ordem em conta.
+fn mesmas(a: &[&Disciplina], b: &[&Disciplina]) -> bool {
+    a.len() == b.len() && a.iter().all(|disciplina| b.contains(disciplina))
+}
+
+fn horarios(disciplina: &Disciplina) -> String {
+    disciplina
+        .sigaa_time
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn ocupantes(disciplinas: &[Disciplina]) -> String {
+    match disciplinas.is_empty() {
+        true => "(vazio)".to_string(),
+        false => disciplinas
+            .iter()
+            .map(|disciplina| disciplina.nome.as_str())
+            .collect::<Vec<_>>()
+            .join(" / "),
+    }
+}
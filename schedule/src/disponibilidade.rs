@@ -0,0 +1,136 @@
+use super::{BlocoLivre, Schedule};
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use stf::{Bloco, SigaaTime, TabelaHorarios};
+
+impl Schedule {
+    /// Horários sem nenhuma disciplina, em ordem de dia e turno.
+    pub fn free_times(&self) -> BTreeSet<SigaaTime> {
+        self.grade
+            .iter()
+            .flatten()
+            .filter(|unity| unity.disciplinas().next().is_none())
+            .map(|unity| unity.horario)
+            .collect()
+    }
+
+    /// Horários livres agrupados em blocos contínuos, em ordem de dia e turno.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::Schedule;
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Cálculo I", "234567M34 234567T12 234567T56 234567N12").unwrap()).unwrap();
+    ///
+    /// let blocos: Vec<String> = schedule.free_blocks().iter().filter(|b| b.dia == stf::Dia::Segunda).map(|b| b.to_string()).collect();
+    /// assert_eq!(blocos, ["2M12", "2M56", "2T34", "2N34"]);
+    /// ```
+    pub fn free_blocks(&self) -> Vec<BlocoLivre> {
+        blocos(&self.free_times())
+    }
+
+    /// Blocos em que todos os cronogramas estão livres e que têm pelo menos `minimo` horários.
+    ///
+    /// Os blocos saem dos mais longos para os mais curtos; entre blocos do mesmo tamanho, vem primeiro
+    /// o que acontece antes na semana. Sem cronogramas, a semana toda é considerada livre.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::Schedule;
+    ///
+    /// let mut ana = Schedule::new();
+    /// ana.insert(Disciplina::new_stringify("Cálculo I", "23456M12 23456T12").unwrap()).unwrap();
+    ///
+    /// let mut bruno = Schedule::new();
+    /// bruno.insert(Disciplina::new_stringify("Física I", "234567M56 234567T56 234567N12").unwrap()).unwrap();
+    ///
+    /// let comuns: Vec<String> = Schedule::common_free_blocks(&[ana, bruno], 2).iter().map(|b| b.to_string()).collect();
+    /// assert_eq!(comuns, ["7M1234", "7T1234"]);
+    /// ```
+    pub fn common_free_blocks(schedules: &[Schedule], minimo: usize) -> Vec<BlocoLivre> {
+        let livres = schedules
+            .iter()
+            .map(Schedule::free_times)
+            .reduce(|comuns, livres| &comuns & &livres)
+            .unwrap_or_else(|| Schedule::new().free_times());
+
+        let mut blocos: Vec<BlocoLivre> = blocos(&livres)
+            .into_iter()
+            .filter(|bloco| bloco.len() >= minimo)
+            .collect();
+        // A ordenação é estável, então blocos do mesmo tamanho continuam em ordem de dia e turno.
+        blocos.sort_by_key(|bloco| std::cmp::Reverse(bloco.len()));
+        blocos
+    }
+}
+
+impl BlocoLivre {
+    /// Quantidade de horários do bloco.
+    pub fn len(&self) -> usize {
+        self.horarios.len()
+    }
+
+    /// Indica se o bloco não tem horários.
+    pub fn is_empty(&self) -> bool {
+        self.horarios.is_empty()
+    }
+
+    /// Trecho do relógio coberto pelo bloco, segundo a tabela.
+    pub fn bloco(&self, tabela: &TabelaHorarios) -> Option<Bloco> {
+        match (self.horarios.first(), self.horarios.last()) {
+            (Some(primeiro), Some(ultimo)) => Some(Bloco::new(self.dia, primeiro.inicio(tabela), ultimo.fim(tabela))),
+            _ => None,
+        }
+    }
+}
+
+impl Display for BlocoLivre {
+    /// Código no formato do SIGAA com todos os períodos do bloco, ex: `2M1234`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.dia)?;
+
+        if let Some(primeiro) = self.horarios.first() {
+            write!(f, "{}", primeiro.turno.periodos()[0].letra())?;
+        }
+
+        for periodo in self
+            .horarios
+            .iter()
+            .flat_map(|horario| horario.turno.periodos())
+        {
+            write!(f, "{}", periodo.numero())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Agrupa horários em blocos do mesmo dia e turno com pares de períodos consecutivos.
+fn blocos(horarios: &BTreeSet<SigaaTime>) -> Vec<BlocoLivre> {
+    let mut blocos: Vec<BlocoLivre> = Vec::new();
+
+    for &horario in horarios {
+        match blocos.last_mut() {
+            Some(bloco)
+                if bloco
+                    .horarios
+                    .last()
+                    .is_some_and(|&anterior| continua(anterior, horario)) =>
+            {
+                bloco.horarios.push(horario)
+            }
+            _ => blocos.push(BlocoLivre { dia: horario.dia, horarios: vec![horario] }),
+        }
+    }
+
+    blocos
+}
+
+fn continua(anterior: SigaaTime, horario: SigaaTime) -> bool {
+    anterior.dia == horario.dia && anterior.turno.periodos()[0].letra() == horario.turno.periodos()[0].letra() && usize::from(anterior.turno) + 1 == usize::from(horario.turno)
+}
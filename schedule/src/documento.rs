@@ -0,0 +1,161 @@
+use super::{Configuracoes, Documento, DocumentoError, Historico, Metadados, Schedule, ScheduleData, ScheduleDataError, ScheduleUnity, VERSAO_DOCUMENTO};
+use serde_json::{json, Value};
+
+/// Migrações entre versões consecutivas: `MIGRACOES[n]` leva um documento da versão `n` para a `n + 1`.
+const MIGRACOES: [fn(Value) -> Value; VERSAO_DOCUMENTO as usize] = [v0_para_v1];
+
+impl Documento {
+    /// Cria um documento na versão atual com as disciplinas e a política do cronograma.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::{Documento, Schedule};
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap()).unwrap();
+    ///
+    /// let json = Documento::new(&schedule).to_json();
+    /// let documento = Documento::from_json(&json).unwrap();
+    ///
+    /// assert!(documento.to_schedule().unwrap() == schedule);
+    /// ```
+    pub fn new(schedule: &Schedule) -> Documento {
+        let mut documento = Documento { versao: VERSAO_DOCUMENTO, metadados: Metadados::default(), semestre: None, disciplinas: Vec::new(), configuracoes: Configuracoes::default(), historico: Historico::default() };
+
+        documento.set_schedule(schedule);
+        documento
+    }
+
+    /// Substitui as disciplinas, a política, o formato da grade e as abreviações fixadas pelas do
+    /// cronograma, mantendo metadados, semestre e tabela.
+    pub fn set_schedule(&mut self, schedule: &Schedule) {
+        self.disciplinas = schedule
+            .disciplines()
+            .into_iter()
+            .cloned()
+            .collect();
+        self.configuracoes.politica = schedule.policy();
+        self.configuracoes.formato = schedule.format().clone();
+        self.configuracoes.abreviacoes = schedule.pinned_abbreviations().clone();
+    }
+
+    /// Lê um documento em JSON de qualquer versão conhecida, migrando-o para a versão atual.
+    ///
+    /// Além dos documentos versionados, aceita os formatos sem versão: a lista de disciplinas salva pelo
+    /// `view-yew` e o `Schedule` serializado diretamente, tanto o atual quanto a matriz inteira das versões antigas.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use schedule::{Documento, VERSAO_DOCUMENTO};
+    ///
+    /// let antigo = r#"[{"nome": "Cálculo I", "abreviacao": "CI", "sigaa_time": [{"dia": "Segunda", "turno": {"Manhã": "Primeiro"}}]}]"#;
+    /// let documento = Documento::from_json(antigo).unwrap();
+    ///
+    /// assert_eq!(documento.versao, VERSAO_DOCUMENTO);
+    /// assert_eq!(documento.disciplinas[0].nome, "Cálculo I");
+    /// ```
+    pub fn from_json(json: &str) -> Result<Documento, DocumentoError> {
+        let valor: Value = serde_json::from_str(json).map_err(|erro| DocumentoError::InvalidJson(erro.to_string()))?;
+        Documento::from_value(valor)
+    }
+
+    /// Igual a `Documento::from_json`, partindo de um valor JSON já lido.
+    pub fn from_value(valor: Value) -> Result<Documento, DocumentoError> {
+        let mut valor = normalizar(valor)?;
+        let versao = versao(&valor)?;
+
+        if versao > VERSAO_DOCUMENTO {
+            return Err(DocumentoError::UnsupportedVersion(versao));
+        }
+
+        for migracao in &MIGRACOES[versao as usize..] {
+            valor = migracao(valor);
+        }
+
+        serde_json::from_value(valor).map_err(|erro| DocumentoError::InvalidJson(erro.to_string()))
+    }
+
+    /// Documento em JSON, na versão atual.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("documento sempre pode ser escrito em JSON")
+    }
+
+    /// Documento como valor JSON, na versão atual.
+    pub fn to_value(&self) -> Value {
+        serde_json::to_value(self).expect("documento sempre pode ser escrito em JSON")
+    }
+
+    /// Remonta o cronograma inserindo as disciplinas do documento com a grade, a política e as abreviações salvas,
+    /// com as mesmas validações da leitura de um `Schedule` (veja `ScheduleData`).
+    pub fn to_schedule(&self) -> Result<Schedule, DocumentoError> {
+        let dados = ScheduleData { disciplinas: self.disciplinas.clone(), formato: self.configuracoes.formato.clone(), politica: self.configuracoes.politica, fixadas: self.configuracoes.abreviacoes.clone() };
+
+        Ok(Schedule::try_from(dados)?)
+    }
+}
+
+/// Versão declarada no documento. A lista de disciplinas sem versão é a versão 0.
+fn versao(valor: &Value) -> Result<u32, DocumentoError> {
+    match valor {
+        Value::Array(_) => Ok(0),
+        Value::Object(campos) => campos
+            .get("versao")
+            .and_then(Value::as_u64)
+            .and_then(|versao| u32::try_from(versao).ok())
+            .ok_or(DocumentoError::UnknownFormat),
+        _ => Err(DocumentoError::UnknownFormat),
+    }
+}
+
+/// Converte o `Schedule` serializado diretamente para um documento da versão 1.
+///
+/// Esse formato não tem versão nem pode ser migrado campo a campo: é a matriz de `ScheduleUnity` das versões
+/// antigas (uma lista de listas, que não se confunde com a lista de disciplinas da versão 0) ou um objeto sem
+/// `versao` com `disciplinas`, o formato atual de `ScheduleData`. O cronograma é remontado e validado, e as
+/// disciplinas, a política, o formato e as abreviações fixadas vão para o documento. Qualquer outro valor é
+/// devolvido sem alteração.
+fn normalizar(valor: Value) -> Result<Value, DocumentoError> {
+    let invalido = |erro: serde_json::Error| DocumentoError::InvalidJson(erro.to_string());
+
+    let schedule = match &valor {
+        Value::Array(linhas) if !linhas.is_empty() && linhas.iter().all(Value::is_array) => {
+            let grade: Vec<Vec<ScheduleUnity>> = serde_json::from_value(valor).map_err(invalido)?;
+            Schedule::try_from(grade)?
+        }
+        Value::Object(campos) if !campos.contains_key("versao") && campos.contains_key("disciplinas") => {
+            let dados: ScheduleData = serde_json::from_value(valor).map_err(invalido)?;
+            Schedule::try_from(dados)?
+        }
+        _ => return Ok(valor),
+    };
+
+    Ok(json!({
+        "versao": 1,
+        "disciplinas": schedule.disciplines(),
+        "configuracoes": {
+            "politica": schedule.policy(),
+            "formato": schedule.format(),
+            "abreviacoes": schedule.pinned_abbreviations(),
+        },
+    }))
+}
+
+impl From<ScheduleDataError> for DocumentoError {
+    fn from(erro: ScheduleDataError) -> Self {
+        match erro {
+            ScheduleDataError::Schedule(erro) => DocumentoError::Schedule(erro),
+            ScheduleDataError::InconsistentGrid(horario) => DocumentoError::InconsistentGrid(horario),
+        }
+    }
+}
+
+/// Versão 0 → 1: a lista de disciplinas passa a ficar dentro de um documento com metadados e configurações.
+fn v0_para_v1(disciplinas: Value) -> Value {
+    json!({
+        "versao": 1,
+        "disciplinas": disciplinas,
+    })
+}
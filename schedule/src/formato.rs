@@ -0,0 +1,119 @@
+use super::{FormatoGrade, Schedule, ScheduleError};
+use stf::{Dia, SigaaTime, Turno};
+
+impl FormatoGrade {
+    /// Cria um formato com os dias e turnos informados, ordenados e sem repetição.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::{FormatoGrade, Schedule};
+    /// use stf::{Dia, Turno};
+    ///
+    /// let formato = FormatoGrade::new(&[Dia::Sabado, Dia::Domingo], &Turno::TODOS);
+    /// let mut schedule = Schedule::with_format(formato);
+    ///
+    /// assert!(schedule.insert(Disciplina::new_stringify("Plantão", "17M1234").unwrap()).is_ok());
+    /// assert!(schedule.insert(Disciplina::new_stringify("Cálculo I", "2M12").unwrap()).is_err());
+    /// ```
+    pub fn new(dias: &[Dia], turnos: &[Turno]) -> FormatoGrade {
+        let mut dias = dias.to_vec();
+        dias.sort();
+        dias.dedup();
+
+        let mut turnos = turnos.to_vec();
+        turnos.sort();
+        turnos.dedup();
+
+        FormatoGrade { dias, turnos }
+    }
+
+    /// Semana inteira, de domingo a sábado, com todos os turnos.
+    pub fn semana_completa() -> FormatoGrade {
+        FormatoGrade::new(&Dia::TODOS, &Turno::TODOS)
+    }
+
+    /// Dias da grade, em ordem.
+    pub fn dias(&self) -> &[Dia] {
+        &self.dias
+    }
+
+    /// Turnos da grade, em ordem.
+    pub fn turnos(&self) -> &[Turno] {
+        &self.turnos
+    }
+
+    /// Indica se o horário faz parte da grade.
+    pub fn contains(&self, sigaa_time: &SigaaTime) -> bool {
+        self.posicao(sigaa_time).is_some()
+    }
+
+    /// Indica se este é o formato padrão (segunda a sábado, todos os turnos).
+    pub fn is_default(&self) -> bool {
+        *self == FormatoGrade::default()
+    }
+
+    /// Linha (turno) e coluna (dia) do horário na grade.
+    pub(crate) fn posicao(&self, sigaa_time: &SigaaTime) -> Option<(usize, usize)> {
+        let linha = self
+            .turnos
+            .iter()
+            .position(|turno| *turno == sigaa_time.turno)?;
+        let coluna = self
+            .dias
+            .iter()
+            .position(|dia| *dia == sigaa_time.dia)?;
+
+        Some((linha, coluna))
+    }
+}
+
+impl Schedule {
+    /// Troca os dias e turnos da grade, mantendo as disciplinas de cada horário.
+    ///
+    /// Se alguma disciplina tiver aula fora do novo formato, nada muda e o erro aponta o primeiro horário
+    /// que ficaria de fora.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::{FormatoGrade, Schedule, ScheduleError};
+    /// use stf::SigaaTime;
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Cálculo I", "7M12").unwrap()).unwrap();
+    ///
+    /// schedule.set_format(FormatoGrade::semana_completa()).unwrap();
+    /// assert!(schedule.get_from_str("1M12").is_some());
+    ///
+    /// let sem_sabado = FormatoGrade::new(&stf::Dia::TODOS[1..6], &stf::Turno::TODOS);
+    /// assert_eq!(schedule.set_format(sem_sabado), Err(ScheduleError::TimeNotFound(SigaaTime::try_from("7M12").unwrap())));
+    /// ```
+    pub fn set_format(&mut self, formato: FormatoGrade) -> Result<(), ScheduleError> {
+        let mut novo = Schedule { politica: self.politica, fixadas: self.fixadas.clone(), ocupados: self.ocupados, ..Schedule::with_format(formato) };
+
+        for unity in self
+            .grade
+            .iter()
+            .flatten()
+            .filter(|unity| unity.disciplinas().next().is_some())
+        {
+            let (linha, coluna) = novo
+                .formato
+                .posicao(&unity.horario)
+                .ok_or(ScheduleError::TimeNotFound(unity.horario))?;
+            novo.grade[linha][coluna] = unity.clone();
+        }
+
+        *self = novo;
+        Ok(())
+    }
+}
+
+impl Default for FormatoGrade {
+    fn default() -> Self {
+        FormatoGrade::new(&Dia::TODOS[1..], &Turno::TODOS)
+    }
+}
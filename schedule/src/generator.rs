@@ -0,0 +1,179 @@
+use super::{FormatoGrade, Oferta, Schedule};
+use class::{Disciplina, DisciplinaErrors};
+use stf::SigaaTimeSet;
+
+impl Oferta {
+    /// Cria uma oferta a partir do nome do componente e dos códigos de horário de cada turma.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use schedule::Oferta;
+    /// let calculo = Oferta::new("Cálculo I", &["24M12", "35T34", "246N12"]).unwrap();
+    /// assert_eq!(calculo.turmas.len(), 3);
+    /// ```
+    pub fn new(nome: &str, horarios: &[&str]) -> Result<Oferta, DisciplinaErrors> {
+        let turmas = horarios
+            .iter()
+            .map(|horario| Disciplina::new_stringify(nome, horario))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Oferta { nome: nome.to_string(), turmas })
+    }
+}
+
+impl Schedule {
+    /// Gera todos os cronogramas sem conflito que escolhem exatamente uma turma de cada oferta.
+    ///
+    /// Os cronogramas saem em ordem: primeiro variam as turmas das ofertas mais ao final da lista.
+    /// Se alguma oferta não tiver turma compatível, o resultado é vazio.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use schedule::{Oferta, Schedule};
+    ///
+    /// let ofertas = [
+    ///     Oferta::new("Cálculo I", &["24M12", "35M12"]).unwrap(),
+    ///     Oferta::new("Física I", &["24M12", "6T1234"]).unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(Schedule::generate(&ofertas).len(), 3);
+    /// ```
+    pub fn generate(ofertas: &[Oferta]) -> Vec<Schedule> {
+        Schedule::generate_up_to(ofertas, usize::MAX)
+    }
+
+    /// Igual a `Schedule::generate`, mas para depois de encontrar `limite` cronogramas.
+    ///
+    /// A busca testa primeiro as ofertas com menos turmas e, a cada escolha, descarta o ramo assim que
+    /// as ofertas restantes deixam de caber nos horários livres, o que mantém a geração rápida mesmo com
+    /// dezenas de componentes.
+    pub fn generate_up_to(ofertas: &[Oferta], limite: usize) -> Vec<Schedule> {
+        let mut busca = Busca::new(ofertas, limite);
+        let mut escolhidas = vec![0; busca.ofertas.len()];
+
+        if busca.viavel(0, SigaaTimeSet::new()) {
+            busca.buscar(0, SigaaTimeSet::new(), &mut escolhidas);
+        }
+
+        busca.cronogramas
+    }
+}
+
+/// Estado da busca em profundidade pelos cronogramas.
+struct Busca<'a> {
+    /// Ofertas ordenadas pela quantidade de turmas, guardando a posição original e os horários de cada turma.
+    ofertas: Vec<(usize, Vec<(SigaaTimeSet, &'a Disciplina)>)>,
+    limite: usize,
+    cronogramas: Vec<Schedule>,
+}
+
+impl<'a> Busca<'a> {
+    fn new(ofertas: &'a [Oferta], limite: usize) -> Busca<'a> {
+        let mut ordenadas: Vec<(usize, Vec<(SigaaTimeSet, &'a Disciplina)>)> = ofertas
+            .iter()
+            .enumerate()
+            .map(|(posicao, oferta)| {
+                let mut turmas: Vec<(SigaaTimeSet, &Disciplina)> = Vec::new();
+                for turma in &oferta.turmas {
+                    if !turmas
+                        .iter()
+                        .any(|(_, existente)| *existente == turma)
+                    {
+                        turmas.push((SigaaTimeSet::from(&turma.sigaa_time), turma));
+                    }
+                }
+                (posicao, turmas)
+            })
+            .collect();
+
+        ordenadas.sort_by_key(|(_, turmas)| turmas.len());
+
+        Busca { ofertas: ordenadas, limite, cronogramas: Vec::new() }
+    }
+
+    fn buscar(&mut self, nivel: usize, ocupado: SigaaTimeSet, escolhidas: &mut Vec<usize>) {
+        if self.cronogramas.len() >= self.limite {
+            return;
+        }
+
+        if nivel == self.ofertas.len() {
+            self.cronogramas.push(self.montar(escolhidas));
+            return;
+        }
+
+        for turma in 0..self.ofertas[nivel].1.len() {
+            let horarios = self.ofertas[nivel].1[turma].0;
+
+            if !horarios.is_disjoint(&ocupado) {
+                continue;
+            }
+
+            let novo = ocupado | horarios;
+
+            if self.viavel(nivel + 1, novo) {
+                escolhidas[nivel] = turma;
+                self.buscar(nivel + 1, novo, escolhidas);
+            }
+        }
+    }
+
+    /// Verifica se as ofertas a partir de `nivel` ainda podem caber na grade já ocupada.
+    ///
+    /// Toda oferta restante precisa de ao menos uma turma compatível, e a soma do menor número de
+    /// horários de cada uma não pode passar da quantidade de horários livres que elas alcançam.
+    fn viavel(&self, nivel: usize, ocupado: SigaaTimeSet) -> bool {
+        let mut necessarios = 0;
+        let mut alcancaveis = SigaaTimeSet::new();
+
+        for (_, turmas) in &self.ofertas[nivel..] {
+            let compativeis = turmas
+                .iter()
+                .filter(|(horarios, _)| horarios.is_disjoint(&ocupado));
+
+            match compativeis
+                .clone()
+                .map(|(horarios, _)| horarios.len())
+                .min()
+            {
+                Some(minimo) => necessarios += minimo,
+                None => return false,
+            }
+
+            alcancaveis = compativeis.fold(alcancaveis, |uniao, (horarios, _)| uniao | *horarios);
+        }
+
+        necessarios <= alcancaveis.len()
+    }
+
+    /// Monta o cronograma com as turmas escolhidas, na ordem original das ofertas.
+    fn montar(&self, escolhidas: &[usize]) -> Schedule {
+        let mut turmas: Vec<(usize, &Disciplina)> = self
+            .ofertas
+            .iter()
+            .zip(escolhidas)
+            .map(|((posicao, turmas), escolhida)| (*posicao, turmas[*escolhida].1))
+            .collect();
+        turmas.sort_by_key(|(posicao, _)| *posicao);
+
+        // Turmas com aula no domingo só cabem numa grade com a semana inteira.
+        let formato = match turmas.iter().all(|(_, turma)| {
+            turma
+                .sigaa_time
+                .iter()
+                .all(|horario| FormatoGrade::default().contains(horario))
+        }) {
+            true => FormatoGrade::default(),
+            false => FormatoGrade::semana_completa(),
+        };
+
+        let mut schedule = Schedule::with_format(formato);
+        for (_, turma) in turmas {
+            // Os horários escolhidos não se cruzam, então a inserção não falha.
+            let _ = schedule.insert(turma.clone());
+        }
+
+        schedule
+    }
+}
@@ -0,0 +1,167 @@
+use super::{Conflict, Edicao, Historico, Schedule, ScheduleError};
+use class::Disciplina;
+use std::collections::BTreeSet;
+use stf::SigaaTime;
+
+impl Edicao {
+    /// Edição que desfaz esta, supondo que esta acabou de ser aplicada.
+    pub fn inverse(&self) -> Edicao {
+        match self {
+            Edicao::Insert(disciplina) => Edicao::Remove(disciplina.clone()),
+            Edicao::Remove(disciplina) => Edicao::Insert(disciplina.clone()),
+            Edicao::Rename { disciplina, nome } => Edicao::Rename { disciplina: renomeada(disciplina, nome), nome: disciplina.nome.clone() },
+            Edicao::Move { disciplina, horarios } => Edicao::Move { disciplina: movida(disciplina, horarios), horarios: disciplina.sigaa_time.clone() },
+        }
+    }
+}
+
+impl Schedule {
+    /// Aplica uma edição ao cronograma, devolvendo os conflitos aceitos como aviso pela política.
+    ///
+    /// `Edicao::Rename` e `Edicao::Move` retiram a disciplina e a inserem de novo com os dados novos; se a
+    /// nova versão não couber, a antiga volta para o lugar e o erro é retornado.
+    pub fn apply(&mut self, edicao: &Edicao) -> Result<Vec<Conflict>, ScheduleError> {
+        match edicao {
+            Edicao::Insert(disciplina) => self.insert_with_warnings(disciplina.clone()),
+            Edicao::Remove(disciplina) => self.remove(disciplina.clone()).map(|_| vec![]),
+            Edicao::Rename { disciplina, nome } => self.replace(disciplina, renomeada(disciplina, nome)),
+            Edicao::Move { disciplina, horarios } => self.replace(disciplina, movida(disciplina, horarios)),
+        }
+    }
+
+    /// Troca `antiga` por `nova`. Se `nova` não couber, o cronograma volta a ser exatamente o que era.
+    fn replace(&mut self, antiga: &Disciplina, nova: Disciplina) -> Result<Vec<Conflict>, ScheduleError> {
+        let anterior = self.clone();
+        self.remove(antiga.clone())?;
+
+        self.insert_with_warnings(nova)
+            .inspect_err(|_| *self = anterior)
+    }
+}
+
+impl Historico {
+    /// Quantidade máxima de edições guardadas para desfazer. As mais antigas são esquecidas primeiro.
+    pub const LIMITE: usize = 100;
+
+    /// Cria um histórico vazio.
+    pub fn new() -> Historico {
+        Historico::default()
+    }
+
+    /// Aplica a edição ao cronograma e a registra no histórico.
+    ///
+    /// Uma edição nova descarta o que havia para refazer. Se a edição falhar, o histórico não muda. Passando de
+    /// `Historico::LIMITE` edições, a mais antiga deixa de poder ser desfeita.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::{Edicao, Historico, Schedule};
+    ///
+    /// let mut schedule = Schedule::new();
+    /// let mut historico = Historico::new();
+    /// let calculo = Disciplina::new_stringify("Cálculo I", "24M12").unwrap();
+    ///
+    /// historico.execute(&mut schedule, Edicao::Insert(calculo.clone())).unwrap();
+    /// historico.execute(&mut schedule, Edicao::Remove(calculo.clone())).unwrap();
+    /// assert_eq!(schedule.get_from_str("2M12").unwrap().disciplina, None);
+    ///
+    /// historico.undo(&mut schedule).unwrap();
+    /// assert_eq!(schedule.get_from_str("2M12").unwrap().disciplina, Some(calculo));
+    ///
+    /// historico.redo(&mut schedule).unwrap();
+    /// assert_eq!(schedule.get_from_str("2M12").unwrap().disciplina, None);
+    /// ```
+    pub fn execute(&mut self, schedule: &mut Schedule, edicao: Edicao) -> Result<Vec<Conflict>, ScheduleError> {
+        let avisos = schedule.apply(&edicao)?;
+
+        self.feitas.push(edicao);
+        self.desfeitas.clear();
+
+        if self.feitas.len() > Historico::LIMITE {
+            self.feitas
+                .drain(..self.feitas.len() - Historico::LIMITE);
+        }
+
+        Ok(avisos)
+    }
+
+    /// Desfaz a última edição aplicada, devolvendo-a. Retorna `Ok(None)` se não houver o que desfazer.
+    pub fn undo(&mut self, schedule: &mut Schedule) -> Result<Option<Edicao>, ScheduleError> {
+        Historico::transferir(schedule, &mut self.feitas, &mut self.desfeitas, Edicao::inverse)
+    }
+
+    /// Refaz a última edição desfeita, devolvendo-a. Retorna `Ok(None)` se não houver o que refazer.
+    pub fn redo(&mut self, schedule: &mut Schedule) -> Result<Option<Edicao>, ScheduleError> {
+        Historico::transferir(schedule, &mut self.desfeitas, &mut self.feitas, Edicao::clone)
+    }
+
+    /// Aplica ao cronograma a edição do topo de `origem` (convertida por `aplicar`) e a move para `destino`.
+    fn transferir(schedule: &mut Schedule, origem: &mut Vec<Edicao>, destino: &mut Vec<Edicao>, aplicar: fn(&Edicao) -> Edicao) -> Result<Option<Edicao>, ScheduleError> {
+        let Some(edicao) = origem.pop() else {
+            return Ok(None);
+        };
+
+        match schedule.apply(&aplicar(&edicao)) {
+            Ok(_) => {
+                destino.push(edicao.clone());
+                Ok(Some(edicao))
+            }
+            Err(erro) => {
+                origem.push(edicao);
+                Err(erro)
+            }
+        }
+    }
+
+    /// Indica se há edição para desfazer.
+    pub fn can_undo(&self) -> bool {
+        !self.feitas.is_empty()
+    }
+
+    /// Indica se há edição para refazer.
+    pub fn can_redo(&self) -> bool {
+        !self.desfeitas.is_empty()
+    }
+
+    /// Edições aplicadas, da mais antiga para a mais recente.
+    pub fn edits(&self) -> &[Edicao] {
+        &self.feitas
+    }
+
+    /// Indica se o histórico não tem nada para desfazer nem refazer.
+    pub fn is_empty(&self) -> bool {
+        self.feitas.is_empty() && self.desfeitas.is_empty()
+    }
+
+    /// Esquece todas as edições, ex: depois de importar um cronograma novo.
+    pub fn clear(&mut self) {
+        self.feitas.clear();
+        self.desfeitas.clear();
+    }
+
+    /// Reconstrói o cronograma aplicando, em ordem, todas as edições feitas sobre `inicial`.
+    ///
+    /// `inicial` é o cronograma de antes da edição mais antiga ainda guardada (veja `Historico::LIMITE`).
+    pub fn replay(&self, mut inicial: Schedule) -> Result<Schedule, ScheduleError> {
+        for edicao in &self.feitas {
+            inicial.apply(edicao)?;
+        }
+
+        Ok(inicial)
+    }
+}
+
+fn renomeada(disciplina: &Disciplina, nome: &str) -> Disciplina {
+    Disciplina { nome: nome.to_string(), ..disciplina.clone() }
+}
+
+/// A disciplina com os novos horários. Detalhes de horários que deixaram de existir são descartados.
+fn movida(disciplina: &Disciplina, horarios: &BTreeSet<SigaaTime>) -> Disciplina {
+    let mut movida = Disciplina { sigaa_time: horarios.clone(), ..disciplina.clone() };
+    movida
+        .detalhes
+        .retain(|detalhe| horarios.contains(&detalhe.horario));
+    movida
+}
@@ -0,0 +1,704 @@
+//! Exportação e leitura de calendários no formato iCalendar (RFC 5545).
+
+use super::semestre::{from_weekday, to_weekday};
+use super::{FormatoGrade, Schedule, ScheduleError, Semestre};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use class::Disciplina;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use stf::{Bloco, Hora, SigaaSlot, SigaaTime, TabelaHorarios};
+
+const PRODID: &str = "-//typed-sigaa-schedule//Horario SIGAA//PT";
+const FORMATO_DATA_HORA: &str = "%Y%m%dT%H%M%S";
+
+/// Um evento (`VEVENT`) de um calendário iCalendar.
+///
+/// As datas são sempre "flutuantes", isto é, no horário local de quem abre o calendário. Fusos
+/// (`TZID`) e o sufixo `Z` são ignorados na leitura.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcsEvent {
+    /// Identificador único do evento.
+    pub uid: Option<String>,
+    /// Título do evento (`SUMMARY`).
+    pub titulo: Option<String>,
+    /// Descrição do evento (`DESCRIPTION`).
+    pub descricao: Option<String>,
+    /// Local do evento (`LOCATION`).
+    pub local: Option<String>,
+    /// Início da primeira ocorrência.
+    pub inicio: NaiveDateTime,
+    /// Fim da primeira ocorrência, se informado.
+    pub fim: Option<NaiveDateTime>,
+    /// Regra de repetição (`RRULE`), se houver.
+    pub recorrencia: Option<Recorrencia>,
+    /// Ocorrências canceladas (`EXDATE`).
+    pub excecoes: Vec<NaiveDateTime>,
+    /// Linha do arquivo onde o evento começa (`BEGIN:VEVENT`).
+    pub linha: usize,
+}
+
+/// Regra de repetição de um evento (`RRULE`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorrencia {
+    /// Frequência da repetição, ex: `WEEKLY`.
+    pub frequencia: String,
+    /// Intervalo entre repetições, em unidades da frequência.
+    pub intervalo: u32,
+    /// Última data em que o evento pode ocorrer.
+    pub ate: Option<NaiveDateTime>,
+    /// Número total de ocorrências.
+    pub contagem: Option<u32>,
+    /// Dias da semana em que o evento se repete (`BYDAY`).
+    pub dias: Vec<Weekday>,
+}
+
+/// Resultado da importação de um calendário iCalendar.
+#[derive(Clone)]
+pub struct IcsImport {
+    /// Cronograma montado com as disciplinas importadas.
+    pub schedule: Schedule,
+    /// Disciplinas reconstruídas, na ordem em que aparecem no arquivo.
+    pub disciplinas: Vec<Disciplina>,
+    /// Eventos que não puderam virar horários do SIGAA, com o motivo.
+    pub rejeitados: Vec<RejectedEvent>,
+}
+
+/// Evento do calendário que ficou de fora da importação.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedEvent {
+    /// Linha do arquivo onde o evento começa.
+    pub linha: usize,
+    /// Título do evento, se houver.
+    pub titulo: Option<String>,
+    /// Por que o evento não foi importado.
+    pub motivo: RejectionReason,
+}
+
+/// Motivos para um evento não ser importado.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RejectionReason {
+    /// Evento sem título, que daria nome à disciplina.
+    MissingTitle,
+    /// Evento sem `DTEND` nem `DURATION`.
+    MissingEnd,
+    /// Evento que não se repete semanalmente.
+    NotWeekly,
+    /// Evento num dia da semana fora da grade do cronograma (ex: domingo).
+    UnsupportedDay(Weekday),
+    /// Início e fim não batem com o começo e o fim de períodos da tabela de horários.
+    NotAligned { inicio: NaiveTime, fim: NaiveTime },
+    /// Os períodos ocupados não formam pares (ex: só `T4`).
+    NotPaired,
+    /// A disciplina conflita com outra já importada.
+    Conflict(ScheduleError),
+}
+
+/// Erro de leitura de um arquivo iCalendar, com a linha onde ocorreu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcsError {
+    pub linha: usize,
+    pub kind: IcsErrorKind,
+}
+
+/// Tipos de erro de leitura de um arquivo iCalendar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IcsErrorKind {
+    /// O arquivo não contém um `VCALENDAR`.
+    MissingCalendar,
+    /// `END` de um componente diferente do que está aberto.
+    UnterminatedComponent(String),
+    /// O arquivo terminou com o componente ainda aberto.
+    UnexpectedEnd(String),
+    /// Linha sem o separador `:` entre nome e valor.
+    InvalidLine,
+    /// Data ou hora em formato desconhecido.
+    InvalidDateTime(String),
+    /// Regra de repetição inválida.
+    InvalidRecurrence(String),
+    /// Propriedade obrigatória ausente no evento.
+    MissingProperty(String),
+}
+
+impl Schedule {
+    /// Exporta o cronograma como um calendário iCalendar.
+    ///
+    /// Cada bloco contínuo de aula de cada disciplina vira um `VEVENT` semanal, começando na
+    /// primeira data do semestre que cai naquele dia e repetindo até o fim do semestre. Os
+    /// feriados viram `EXDATE`s e os horários no relógio vêm da `tabela`.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use class::Disciplina;
+    /// use schedule::{ics, Schedule, Semestre};
+    /// use stf::TabelaHorarios;
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap()).unwrap();
+    ///
+    /// let semestre = Semestre::new(NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(), NaiveDate::from_ymd_opt(2025, 7, 12).unwrap()).unwrap();
+    /// let calendario = schedule.to_ics(&semestre, &TabelaHorarios::default());
+    ///
+    /// assert_eq!(ics::parse(&calendario).unwrap().len(), 2);
+    /// ```
+    pub fn to_ics(&self, semestre: &Semestre, tabela: &TabelaHorarios) -> String {
+        let dtstamp = semestre.inicio.and_time(NaiveTime::MIN);
+        let mut linhas = vec!["BEGIN:VCALENDAR".to_string(), "VERSION:2.0".to_string(), format!("PRODID:{}", PRODID), "CALSCALE:GREGORIAN".to_string()];
+
+        for disciplina in self.disciplines() {
+            for bloco in disciplina.blocos(tabela) {
+                if let Some(evento) = IcsEvent::from_bloco(disciplina, &bloco, semestre, tabela) {
+                    linhas.extend(evento.to_lines(dtstamp));
+                }
+            }
+        }
+
+        linhas.push("END:VCALENDAR".to_string());
+
+        linhas
+            .iter()
+            .map(|linha| fold(linha) + "\r\n")
+            .collect()
+    }
+}
+
+impl Schedule {
+    /// Reconstrói um cronograma a partir de um calendário iCalendar.
+    ///
+    /// Cada evento semanal é convertido em horários do SIGAA pelo dia da semana e pelo horário de
+    /// início e fim, usando a `tabela`. Eventos com o mesmo título são juntados em uma única
+    /// disciplina. Eventos que não se encaixam em nenhum período, que caem fora do `formato` (ex: domingo
+    /// na grade padrão) ou que conflitam com outra disciplina não são descartados em silêncio: ficam em
+    /// `IcsImport::rejeitados`.
+    ///
+    /// Retorna erro apenas se o arquivo em si for inválido.
+    pub fn from_ics(input: &str, tabela: &TabelaHorarios, formato: &FormatoGrade) -> Result<IcsImport, IcsError> {
+        let mut schedule = Schedule::with_format(formato.clone());
+        let mut horarios: Vec<(String, usize, BTreeSet<SigaaTime>)> = Vec::new();
+        let mut rejeitados = Vec::new();
+
+        for evento in parse(input)? {
+            let titulo = match &evento.titulo {
+                Some(titulo) if !titulo.trim().is_empty() => titulo.trim().to_string(),
+                _ => {
+                    rejeitados.push(RejectedEvent::new(&evento, RejectionReason::MissingTitle));
+                    continue;
+                }
+            };
+
+            let sigaa_times = evento
+                .sigaa_times(tabela)
+                .and_then(|sigaa_times| {
+                    match sigaa_times
+                        .iter()
+                        .find(|sigaa_time| !schedule.format().contains(sigaa_time))
+                    {
+                        Some(fora) => Err(RejectionReason::UnsupportedDay(to_weekday(fora.dia))),
+                        None => Ok(sigaa_times),
+                    }
+                });
+
+            match sigaa_times {
+                Ok(sigaa_times) => match horarios
+                    .iter_mut()
+                    .find(|(nome, _, _)| *nome == titulo)
+                {
+                    Some((_, _, existentes)) => existentes.extend(sigaa_times),
+                    None => horarios.push((titulo, evento.linha, sigaa_times)),
+                },
+                Err(motivo) => rejeitados.push(RejectedEvent::new(&evento, motivo)),
+            }
+        }
+
+        let mut disciplinas = Vec::new();
+
+        for (nome, linha, sigaa_times) in horarios {
+            let disciplina = Disciplina::new(&nome, sigaa_times);
+
+            match schedule.insert(disciplina.clone()) {
+                Ok(()) => disciplinas.push(disciplina),
+                Err(erro) => rejeitados.push(RejectedEvent { linha, titulo: Some(nome), motivo: RejectionReason::Conflict(erro) }),
+            }
+        }
+
+        Ok(IcsImport { schedule, disciplinas, rejeitados })
+    }
+}
+
+impl IcsEvent {
+    /// Horários do SIGAA ocupados pelo evento a cada semana.
+    ///
+    /// Os dias vêm do `BYDAY` da recorrência ou, na falta dele, do dia de `inicio`. O horário de
+    /// início e fim precisa coincidir com o começo e o fim de períodos da `tabela`.
+    pub fn sigaa_times(&self, tabela: &TabelaHorarios) -> Result<BTreeSet<SigaaTime>, RejectionReason> {
+        let termino = self.fim.ok_or(RejectionReason::MissingEnd)?;
+
+        let dias = match &self.recorrencia {
+            Some(recorrencia) if recorrencia.frequencia != "WEEKLY" || recorrencia.intervalo != 1 => return Err(RejectionReason::NotWeekly),
+            Some(recorrencia) if !recorrencia.dias.is_empty() => recorrencia.dias.clone(),
+            Some(_) => vec![self.inicio.weekday()],
+            None => return Err(RejectionReason::NotWeekly),
+        };
+
+        let nao_alinhado = RejectionReason::NotAligned { inicio: self.inicio.time(), fim: termino.time() };
+        let periodos = match (from_time(self.inicio.time()), from_time(termino.time())) {
+            (Some(inicio), Some(fim)) if self.inicio.date() == termino.date() => tabela
+                .periodos_entre(inicio, fim)
+                .ok_or(nao_alinhado)?,
+            _ => return Err(nao_alinhado),
+        };
+
+        let mut slots = BTreeSet::new();
+        for weekday in dias {
+            let dia = from_weekday(weekday);
+            slots.extend(
+                periodos
+                    .iter()
+                    .map(|periodo| SigaaSlot::new(dia, *periodo)),
+            );
+        }
+
+        SigaaTime::from_slots(&slots).map_err(|_| RejectionReason::NotPaired)
+    }
+
+    /// Monta o evento semanal de um bloco de aula dentro do semestre.
+    ///
+    /// O UID identifica a turma (veja `identificador`), então turmas de mesmo nome sobrepostas não
+    /// viram o mesmo evento. O local é o do primeiro horário do bloco, achado pela `tabela`. Retorna
+    /// `None` se o dia do bloco não ocorrer nenhuma vez no semestre.
+    pub fn from_bloco(disciplina: &Disciplina, bloco: &Bloco, semestre: &Semestre, tabela: &TabelaHorarios) -> Option<IcsEvent> {
+        let data = semestre.primeiro(bloco.dia)?;
+        let primeiro = disciplina
+            .sigaa_time
+            .iter()
+            .find(|sigaa_time| sigaa_time.dia == bloco.dia && sigaa_time.inicio(tabela) == bloco.inicio);
+
+        Some(IcsEvent {
+            uid: Some(format!("{}-{}-{}-{:02}{:02}@typed-sigaa-schedule", disciplina.abreviacao, identificador(disciplina), bloco.dia, bloco.inicio.hora, bloco.inicio.minuto)),
+            titulo: Some(disciplina.nome.clone()),
+            descricao: Some(disciplina.to_string()),
+            local: primeiro
+                .and_then(|sigaa_time| disciplina.local_em(sigaa_time))
+                .map(str::to_string),
+            inicio: data.and_time(to_time(bloco.inicio)),
+            fim: Some(data.and_time(to_time(bloco.fim))),
+            recorrencia: Some(Recorrencia::semanal(semestre.fim)),
+            excecoes: semestre
+                .feriados_em(bloco.dia)
+                .map(|feriado| feriado.and_time(to_time(bloco.inicio)))
+                .collect(),
+            linha: 0,
+        })
+    }
+
+    /// Linhas do evento no formato iCalendar, ainda sem dobra.
+    fn to_lines(&self, dtstamp: NaiveDateTime) -> Vec<String> {
+        let mut linhas = vec!["BEGIN:VEVENT".to_string()];
+
+        if let Some(uid) = &self.uid {
+            linhas.push(format!("UID:{}", escape(uid)));
+        }
+
+        linhas.push(format!("DTSTAMP:{}Z", dtstamp.format(FORMATO_DATA_HORA)));
+        linhas.push(format!("DTSTART:{}", self.inicio.format(FORMATO_DATA_HORA)));
+
+        if let Some(fim) = self.fim {
+            linhas.push(format!("DTEND:{}", fim.format(FORMATO_DATA_HORA)));
+        }
+
+        if let Some(recorrencia) = &self.recorrencia {
+            linhas.push(format!("RRULE:{}", recorrencia));
+        }
+
+        if !self.excecoes.is_empty() {
+            let excecoes: Vec<String> = self
+                .excecoes
+                .iter()
+                .map(|excecao| excecao.format(FORMATO_DATA_HORA).to_string())
+                .collect();
+            linhas.push(format!("EXDATE:{}", excecoes.join(",")));
+        }
+
+        for (nome, valor) in [("SUMMARY", &self.titulo), ("DESCRIPTION", &self.descricao), ("LOCATION", &self.local)] {
+            if let Some(valor) = valor {
+                linhas.push(format!("{}:{}", nome, escape(valor)));
+            }
+        }
+
+        linhas.push("END:VEVENT".to_string());
+        linhas
+    }
+}
+
+impl RejectedEvent {
+    fn new(evento: &IcsEvent, motivo: RejectionReason) -> RejectedEvent {
+        RejectedEvent { linha: evento.linha, titulo: evento.titulo.clone(), motivo }
+    }
+}
+
+impl Recorrencia {
+    /// Repetição semanal até o fim do dia informado.
+    pub fn semanal(ate: NaiveDate) -> Recorrencia {
+        Recorrencia { frequencia: "WEEKLY".to_string(), intervalo: 1, ate: ate.and_hms_opt(23, 59, 59), contagem: None, dias: vec![] }
+    }
+}
+
+impl Display for Recorrencia {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FREQ={}", self.frequencia)?;
+
+        if self.intervalo != 1 {
+            write!(f, ";INTERVAL={}", self.intervalo)?;
+        }
+        if let Some(ate) = self.ate {
+            write!(f, ";UNTIL={}", ate.format(FORMATO_DATA_HORA))?;
+        }
+        if let Some(contagem) = self.contagem {
+            write!(f, ";COUNT={}", contagem)?;
+        }
+        if !self.dias.is_empty() {
+            let dias: Vec<&str> = self
+                .dias
+                .iter()
+                .map(|dia| weekday_code(*dia))
+                .collect();
+            write!(f, ";BYDAY={}", dias.join(","))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for Recorrencia {
+    type Error = IcsErrorKind;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let invalida = || IcsErrorKind::InvalidRecurrence(value.to_string());
+        let mut recorrencia = Recorrencia { frequencia: String::new(), intervalo: 1, ate: None, contagem: None, dias: vec![] };
+
+        for parte in value.split(';').filter(|parte| !parte.is_empty()) {
+            let (chave, valor) = parte.split_once('=').ok_or_else(invalida)?;
+
+            match chave.to_ascii_uppercase().as_str() {
+                "FREQ" => recorrencia.frequencia = valor.to_ascii_uppercase(),
+                "INTERVAL" => recorrencia.intervalo = valor.parse().map_err(|_| invalida())?,
+                "UNTIL" => recorrencia.ate = Some(parse_data_hora(valor)?),
+                "COUNT" => recorrencia.contagem = Some(valor.parse().map_err(|_| invalida())?),
+                "BYDAY" => {
+                    recorrencia.dias = valor
+                        .split(',')
+                        .map(|dia| parse_weekday(dia).ok_or_else(invalida))
+                        .collect::<Result<_, _>>()?
+                }
+                _ => {}
+            }
+        }
+
+        match recorrencia.frequencia.is_empty() {
+            true => Err(invalida()),
+            false => Ok(recorrencia),
+        }
+    }
+}
+
+impl Display for IcsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "linha {}: {:?}", self.linha, self.kind)
+    }
+}
+
+impl std::error::Error for IcsError {}
+
+/// Lê todos os eventos de um arquivo iCalendar.
+///
+/// É um leitor propositalmente simples: entende dobra de linhas, escapes de texto, `DTSTART`,
+/// `DTEND`, `DURATION`, `RRULE` e `EXDATE`, e ignora componentes que não sejam `VEVENT`
+/// (como `VTIMEZONE` e `VALARM`).
+pub fn parse(input: &str) -> Result<Vec<IcsEvent>, IcsError> {
+    let mut eventos = Vec::new();
+    let mut componentes: Vec<(String, usize)> = Vec::new();
+    let mut evento: Option<EventoParcial> = None;
+    let mut tem_calendario = false;
+
+    for (linha, conteudo) in unfold(input) {
+        if conteudo.trim().is_empty() {
+            continue;
+        }
+
+        let erro = |kind| IcsError { linha, kind };
+        let (nome, valor) = split_property(&conteudo).ok_or(erro(IcsErrorKind::InvalidLine))?;
+
+        match nome.as_str() {
+            "BEGIN" => {
+                let componente = valor.to_ascii_uppercase();
+                tem_calendario |= componente == "VCALENDAR";
+
+                if componente == "VEVENT"
+                    && componentes
+                        .last()
+                        .is_some_and(|(nome, _)| nome == "VCALENDAR")
+                {
+                    evento = Some(EventoParcial::new(linha));
+                }
+                componentes.push((componente, linha));
+            }
+            "END" => {
+                let componente = valor.to_ascii_uppercase();
+
+                match componentes.pop() {
+                    Some((aberto, _)) if aberto == componente => {}
+                    Some((aberto, _)) => return Err(erro(IcsErrorKind::UnterminatedComponent(aberto))),
+                    None => return Err(erro(IcsErrorKind::UnterminatedComponent(componente))),
+                }
+
+                if componente == "VEVENT" {
+                    if let Some(parcial) = evento.take() {
+                        eventos.push(parcial.finish().map_err(erro)?);
+                    }
+                }
+            }
+            _ if componentes
+                .last()
+                .is_some_and(|(nome, _)| nome == "VEVENT") =>
+            {
+                if let Some(parcial) = evento.as_mut() {
+                    parcial.set(&nome, &valor).map_err(erro)?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((aberto, linha)) = componentes.pop() {
+        return Err(IcsError { linha, kind: IcsErrorKind::UnexpectedEnd(aberto) });
+    }
+
+    match tem_calendario {
+        true => Ok(eventos),
+        false => Err(IcsError { linha: 1, kind: IcsErrorKind::MissingCalendar }),
+    }
+}
+
+/// Evento em construção durante a leitura.
+struct EventoParcial {
+    evento: IcsEvent,
+    tem_inicio: bool,
+    duracao: Option<chrono::Duration>,
+}
+
+impl EventoParcial {
+    fn new(linha: usize) -> EventoParcial {
+        EventoParcial { evento: IcsEvent { uid: None, titulo: None, descricao: None, local: None, inicio: NaiveDateTime::MIN, fim: None, recorrencia: None, excecoes: vec![], linha }, tem_inicio: false, duracao: None }
+    }
+
+    fn set(&mut self, nome: &str, valor: &str) -> Result<(), IcsErrorKind> {
+        let evento = &mut self.evento;
+
+        match nome {
+            "UID" => evento.uid = Some(unescape(valor)),
+            "SUMMARY" => evento.titulo = Some(unescape(valor)),
+            "DESCRIPTION" => evento.descricao = Some(unescape(valor)),
+            "LOCATION" => evento.local = Some(unescape(valor)),
+            "DTSTART" => {
+                evento.inicio = parse_data_hora(valor)?;
+                self.tem_inicio = true;
+            }
+            "DTEND" => evento.fim = Some(parse_data_hora(valor)?),
+            "DURATION" => self.duracao = Some(parse_duracao(valor)?),
+            "RRULE" => evento.recorrencia = Some(valor.try_into()?),
+            "EXDATE" => {
+                for excecao in valor.split(',') {
+                    evento.excecoes.push(parse_data_hora(excecao)?);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<IcsEvent, IcsErrorKind> {
+        if !self.tem_inicio {
+            return Err(IcsErrorKind::MissingProperty("DTSTART".to_string()));
+        }
+
+        if let (None, Some(duracao)) = (self.evento.fim, self.duracao) {
+            self.evento.fim = Some(self.evento.inicio + duracao);
+        }
+
+        Ok(self.evento)
+    }
+}
+
+/// Junta as linhas dobradas, guardando o número da primeira linha física de cada uma.
+fn unfold(input: &str) -> Vec<(usize, String)> {
+    let mut linhas: Vec<(usize, String)> = Vec::new();
+
+    for (index, linha) in input.lines().enumerate() {
+        match (linha.strip_prefix([' ', '\t']), linhas.last_mut()) {
+            (Some(continuacao), Some((_, anterior))) => anterior.push_str(continuacao),
+            _ => linhas.push((index + 1, linha.to_string())),
+        }
+    }
+
+    linhas
+}
+
+/// Divide uma linha de conteúdo em nome e valor, descartando os parâmetros (ex: `TZID`).
+fn split_property(linha: &str) -> Option<(String, String)> {
+    let mut entre_aspas = false;
+    let separador = linha.char_indices().find(|(_, c)| {
+        if *c == '"' {
+            entre_aspas = !entre_aspas;
+        }
+        *c == ':' && !entre_aspas
+    })?;
+
+    let (cabecalho, valor) = (&linha[..separador.0], &linha[separador.0 + 1..]);
+    let nome = cabecalho
+        .split(';')
+        .next()?
+        .trim()
+        .to_ascii_uppercase();
+
+    Some((nome, valor.to_string()))
+}
+
+/// Dobra linhas longas em pedaços de no máximo 75 bytes, como pede a RFC 5545.
+fn fold(linha: &str) -> String {
+    let mut dobrada = String::new();
+    let mut tamanho = 0;
+
+    for c in linha.chars() {
+        if tamanho + c.len_utf8() > 75 {
+            dobrada.push_str("\r\n ");
+            tamanho = 1;
+        }
+        dobrada.push(c);
+        tamanho += c.len_utf8();
+    }
+
+    dobrada
+}
+
+/// Parte do UID que separa turmas de mesmo nome: código e turma do SIGAA, quando a disciplina tem os dois,
+/// ou um hash FNV-1a do nome com os horários, que não muda entre exportações.
+fn identificador(disciplina: &Disciplina) -> String {
+    match (&disciplina.codigo, &disciplina.turma) {
+        (Some(codigo), Some(turma)) => format!("{codigo}-T{turma}"),
+        _ => {
+            let texto = format!("{} {}", disciplina.nome, SigaaTime::format_codes(&disciplina.sigaa_time));
+            let hash = texto
+                .bytes()
+                .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3));
+            format!("{hash:016x}")
+        }
+    }
+}
+
+fn escape(texto: &str) -> String {
+    texto
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape(texto: &str) -> String {
+    let mut saida = String::new();
+    let mut chars = texto.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            saida.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n' | 'N') => saida.push('\n'),
+            Some(escapado) => saida.push(escapado),
+            None => saida.push('\\'),
+        }
+    }
+
+    saida
+}
+
+fn parse_data_hora(valor: &str) -> Result<NaiveDateTime, IcsErrorKind> {
+    let valor = valor.trim().trim_end_matches('Z');
+    let invalida = || IcsErrorKind::InvalidDateTime(valor.to_string());
+
+    match valor.len() {
+        8 => NaiveDate::parse_from_str(valor, "%Y%m%d")
+            .map(|data| data.and_time(NaiveTime::MIN))
+            .map_err(|_| invalida()),
+        _ => NaiveDateTime::parse_from_str(valor, FORMATO_DATA_HORA).map_err(|_| invalida()),
+    }
+}
+
+/// Lê uma duração no formato `P[n]W` ou `P[n]DT[n]H[n]M[n]S`.
+fn parse_duracao(valor: &str) -> Result<chrono::Duration, IcsErrorKind> {
+    let invalida = || IcsErrorKind::InvalidDateTime(valor.to_string());
+    let corpo = valor
+        .trim()
+        .trim_start_matches('+')
+        .strip_prefix('P')
+        .ok_or_else(invalida)?;
+
+    let mut segundos = 0i64;
+    let mut numero = String::new();
+
+    for c in corpo.chars() {
+        let unidade = match c {
+            '0'..='9' => {
+                numero.push(c);
+                continue;
+            }
+            'T' => continue,
+            'W' => 7 * 24 * 3600,
+            'D' => 24 * 3600,
+            'H' => 3600,
+            'M' => 60,
+            'S' => 1,
+            _ => return Err(invalida()),
+        };
+
+        segundos += numero.parse::<i64>().map_err(|_| invalida())? * unidade;
+        numero.clear();
+    }
+
+    Ok(chrono::Duration::seconds(segundos))
+}
+
+fn to_time(hora: Hora) -> NaiveTime {
+    NaiveTime::from_hms_opt(hora.hora as u32, hora.minuto as u32, 0).unwrap_or(NaiveTime::MIN)
+}
+
+fn from_time(hora: NaiveTime) -> Option<Hora> {
+    match hora.second() {
+        0 => Hora::new(hora.hour() as u8, hora.minute() as u8).ok(),
+        _ => None,
+    }
+}
+
+fn weekday_code(dia: Weekday) -> &'static str {
+    match dia {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn parse_weekday(codigo: &str) -> Option<Weekday> {
+    // BYDAY pode vir com prefixo numérico (ex: `1MO`), que não interessa para eventos semanais.
+    let codigo = codigo.trim_start_matches(|c: char| c.is_ascii_digit() || c == '+' || c == '-');
+
+    [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun]
+        .into_iter()
+        .find(|dia| weekday_code(*dia).eq_ignore_ascii_case(codigo))
+}
@@ -1,39 +1,394 @@
+use chrono::{NaiveDate, NaiveDateTime};
 use class::Disciplina;
 use serde::{Deserialize, Serialize};
-use stf::{SigaaTime, SigaaTimeErrors};
+use std::collections::{BTreeMap, BTreeSet};
+use stf::{Dia, SigaaTime, SigaaTimeErrors, SigaaTimeSet, TabelaHorarios, Turno};
 
 /// Representa uma unidade de horário em um cronograma.
 ///
 /// Uma `ScheduleUnity` contém um horário específico (`SigaaTime`) e uma disciplina opcional
-/// associada a esse horário.
+/// associada a esse horário. Quando o cronograma permite sobreposição (veja `OverlapPolicy`),
+/// as demais disciplinas do mesmo horário ficam em `sobrepostas`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScheduleUnity {
     /// O horário específico para esta unidade.
     pub horario: SigaaTime,
     /// A disciplina associada a este horário, se houver.
     pub disciplina: Option<Disciplina>,
+    /// Outras disciplinas que dividem este horário com `disciplina`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sobrepostas: Vec<Disciplina>,
 }
 
 /// Representa um cronograma composto por uma matriz de unidades de horário.
 ///
 /// O cronograma é uma coleção bidimensional de `ScheduleUnity`, onde cada `Vec` representa uma linha
-/// de horários.
+/// de horários (um turno do `FormatoGrade`), junto com a política de sobreposição usada nas inserções.
+///
+/// A grade não é serializada: o cronograma é salvo como um `ScheduleData` e remontado na leitura, que também
+/// aceita a matriz de `ScheduleUnity` escrita pelas versões antigas.
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
-pub struct Schedule(Vec<Vec<ScheduleUnity>>);
+#[serde(try_from = "ScheduleSalvo", into = "ScheduleData")]
+pub struct Schedule {
+    grade: Vec<Vec<ScheduleUnity>>,
+    /// Dias e turnos das colunas e linhas da grade.
+    formato: FormatoGrade,
+    politica: OverlapPolicy,
+    /// Abreviações escolhidas pelo usuário, por nome de disciplina.
+    fixadas: BTreeMap<String, String>,
+    /// Horários com alguma disciplina, para checar conflitos sem percorrer a grade.
+    ocupados: SigaaTimeSet,
+}
+
+/// Forma serializada de um `Schedule`: as disciplinas e as configurações, sem a grade.
+///
+/// Na leitura, a grade é remontada inserindo as disciplinas na ordem salva, então dados que não formam um
+/// cronograma válido são recusados com um `ScheduleDataError`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleData {
+    #[serde(default)]
+    pub disciplinas: Vec<Disciplina>,
+    #[serde(default, skip_serializing_if = "FormatoGrade::is_default")]
+    pub formato: FormatoGrade,
+    #[serde(default)]
+    pub politica: OverlapPolicy,
+    /// Abreviações escolhidas pelo usuário, por nome de disciplina.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fixadas: BTreeMap<String, String>,
+}
+
+/// Formas de um `Schedule` aceitas na leitura.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScheduleSalvo {
+    /// Formato atual.
+    Dados(ScheduleData),
+    /// Matriz inteira, como o `Schedule` era serializado antes de existir o `ScheduleData`.
+    Grade(Vec<Vec<ScheduleUnity>>),
+}
+
+/// Dias e turnos que formam a grade de um cronograma.
+///
+/// O padrão é a semana do SIGAA de segunda a sábado com os oito turnos. Instituições com aulas no domingo,
+/// ou que não usam o sábado ou a noite, podem montar o seu e criar o cronograma com `Schedule::with_format`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormatoGrade {
+    /// Colunas da grade, em ordem.
+    dias: Vec<Dia>,
+    /// Linhas da grade, em ordem.
+    turnos: Vec<Turno>,
+}
+
+/// O que fazer quando uma disciplina é inserida em horários já ocupados.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OverlapPolicy {
+    /// Recusa a inserção (padrão).
+    #[default]
+    Reject,
+    /// Insere, mas devolve os conflitos como aviso.
+    Warn,
+    /// Insere sem avisar, ex: aula de laboratório que divide horário com a teórica.
+    Allow,
+}
+
+/// Conflito de horário entre uma disciplina e o cronograma.
+///
+/// Indica o horário disputado e a disciplina que já o ocupa.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conflict {
+    /// Horário disputado.
+    pub horario: SigaaTime,
+    /// Disciplina que já ocupa o horário.
+    pub disciplina: Disciplina,
+}
+
+/// Componente curricular ofertado em várias turmas, usado na geração de cronogramas.
+///
+/// Cada turma é uma `Disciplina` com o mesmo nome e os horários daquela turma.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Oferta {
+    /// Nome do componente.
+    pub nome: String,
+    /// Turmas alternativas; o gerador escolhe exatamente uma.
+    pub turmas: Vec<Disciplina>,
+}
+
+/// Critérios de preferência usados para pontuar um cronograma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Criterio {
+    /// Horários vagos entre a primeira e a última aula de um mesmo dia.
+    Janelas,
+    /// Aulas no primeiro horário da manhã (`M12`).
+    Cedo,
+    /// Aulas no sábado.
+    Sabado,
+    /// Aulas no domingo, para grades que o incluem.
+    Domingo,
+    /// Aulas no turno da noite.
+    Noite,
+    /// Dias com uma única disciplina.
+    DiaIsolado,
+    /// Dias sem nenhuma aula.
+    DiaLivre,
+}
+
+/// Pesos de cada critério na pontuação de um cronograma.
+///
+/// Todos os critérios são penalidades, exceto `dia_livre`, que é um bônus. Um peso zero ignora o critério.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Pesos {
+    pub janela: f64,
+    pub cedo: f64,
+    pub sabado: f64,
+    pub domingo: f64,
+    pub noite: f64,
+    pub dia_isolado: f64,
+    pub dia_livre: f64,
+}
+
+/// Contribuição de um critério para a pontuação de um cronograma.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemPontuacao {
+    pub criterio: Criterio,
+    /// Quantas vezes o critério ocorre no cronograma.
+    pub ocorrencias: u32,
+    /// Pontos somados ao total (negativos para penalidades).
+    pub pontos: f64,
+}
+
+/// Pontuação de um cronograma, com o detalhamento por critério. Quanto maior, melhor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pontuacao {
+    pub total: f64,
+    pub criterios: Vec<ItemPontuacao>,
+}
+
+/// Período letivo em que o cronograma é válido.
+///
+/// Guarda a data de início, a data de fim e os feriados (dias sem aula) do semestre.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Semestre {
+    /// Primeiro dia letivo.
+    pub inicio: NaiveDate,
+    /// Último dia letivo.
+    pub fim: NaiveDate,
+    /// Datas sem aula dentro do semestre.
+    pub feriados: BTreeSet<NaiveDate>,
+}
+
+/// Diferenças entre duas versões de um cronograma.
+///
+/// As disciplinas são comparadas pelo nome: uma disciplina que continua com o mesmo nome mas mudou de
+/// horário (ex: troca de turma) aparece em `movidas`, e não como removida e adicionada.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleDiff {
+    /// Disciplinas que só existem na versão nova.
+    pub adicionadas: Vec<Disciplina>,
+    /// Disciplinas que só existem na versão antiga.
+    pub removidas: Vec<Disciplina>,
+    /// Disciplinas que mudaram de horário.
+    pub movidas: Vec<Alteracao>,
+    /// Disciplinas com os mesmos horários, mas outros dados alterados (turma, local, professores...).
+    pub alteradas: Vec<Alteracao>,
+    /// Horários da grade cujas disciplinas mudaram, em ordem de turno e dia.
+    pub horarios: Vec<MudancaHorario>,
+}
+
+/// Uma disciplina antes e depois de uma alteração.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Alteracao {
+    pub antes: Disciplina,
+    pub depois: Disciplina,
+}
+
+/// Disciplinas de um horário da grade antes e depois de uma alteração.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MudancaHorario {
+    pub horario: SigaaTime,
+    pub antes: Vec<Disciplina>,
+    pub depois: Vec<Disciplina>,
+}
+
+/// Horários livres consecutivos de um mesmo dia e turno (veja `Schedule::free_blocks`).
+///
+/// Segue a mesma regra de continuidade de `TabelaHorarios::blocos`: `M56` e `T12` não formam um bloco,
+/// porque há o intervalo do almoço entre eles.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlocoLivre {
+    pub dia: Dia,
+    /// Horários do bloco, em ordem.
+    pub horarios: Vec<SigaaTime>,
+}
+
+/// Edição de um cronograma que pode ser desfeita e refeita (veja `Historico`).
+///
+/// Cada edição guarda a disciplina como estava antes de ser aplicada, o que basta para montar a inversa.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Edicao {
+    /// Insere a disciplina.
+    Insert(Disciplina),
+    /// Remove a disciplina.
+    Remove(Disciplina),
+    /// Troca o nome da disciplina.
+    Rename { disciplina: Disciplina, nome: String },
+    /// Troca os horários da disciplina, ex: mudança de turma.
+    Move { disciplina: Disciplina, horarios: BTreeSet<SigaaTime> },
+}
+
+/// Histórico de edições de um cronograma, com desfazer e refazer.
+///
+/// As edições ficam guardadas em ordem, então o histórico pode ser salvo junto do `Documento` e o
+/// cronograma pode ser reconstruído a partir delas com `Historico::replay`. Abreviações fixadas com
+/// `Schedule::pin_abbreviation` são configuração, não edição: não entram no histórico nem são desfeitas.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Historico {
+    /// Edições aplicadas, da mais antiga para a mais recente.
+    #[serde(default)]
+    feitas: Vec<Edicao>,
+    /// Edições desfeitas que ainda podem ser refeitas; a próxima a refazer fica no final.
+    #[serde(default)]
+    desfeitas: Vec<Edicao>,
+}
+
+/// Resultado da junção de duas cópias editadas de um mesmo cronograma (veja `Schedule::merge`).
+#[derive(Clone)]
+pub struct Merge {
+    /// Cronograma com as alterações das duas cópias que puderam ser combinadas.
+    pub schedule: Schedule,
+    /// Alterações que não puderam ser combinadas automaticamente.
+    pub conflitos: Vec<MergeConflict>,
+}
+
+/// Conflito encontrado ao juntar duas cópias de um cronograma.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MergeConflict {
+    /// As duas cópias alteraram a mesma disciplina de formas diferentes. A versão `nossa` foi mantida.
+    BothChanged { nome: String, nossa: Vec<Disciplina>, deles: Vec<Disciplina> },
+    /// A disciplina ficou de fora porque ocupa horários já tomados por outras disciplinas da junção, ou
+    /// horários fora da grade da junção (nesse caso, `conflitos` fica vazio).
+    SlotTaken { disciplina: Disciplina, conflitos: Vec<Conflict> },
+}
+
+/// Quantas aulas cada dia da semana tem num período letivo, já descontados os feriados.
+///
+/// O padrão é um semestre de 18 semanas sem feriados, em que cada `SigaaTime` de 100 minutos soma 30 horas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiasLetivos {
+    /// Número de aulas de domingo a sábado, na ordem de `Dia`.
+    aulas: [u32; 7],
+}
+
+/// Carga horária de um cronograma, calculada com a duração real de cada período.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CargaHoraria {
+    /// Tempo em aula por semana. Horários sobrepostos contam uma vez só.
+    pub minutos_semanais: u32,
+    /// Tempo em aula no período letivo inteiro.
+    pub minutos_periodo: u32,
+    /// Carga de cada disciplina, na ordem em que aparecem na grade.
+    pub disciplinas: Vec<CargaDisciplina>,
+}
+
+/// Carga horária de uma disciplina.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CargaDisciplina {
+    pub disciplina: Disciplina,
+    pub minutos_semanais: u32,
+    pub minutos_periodo: u32,
+}
+
+/// Versão atual do formato de `Documento`.
+pub const VERSAO_DOCUMENTO: u32 = 1;
+
+/// Cronograma salvo em disco (ou no LocalStorage), com versão explícita do formato.
+///
+/// Guarda apenas as disciplinas e as configurações; a grade é remontada ao carregar. Documentos de versões
+/// anteriores, incluindo a lista de disciplinas sem versão, são migrados por `Documento::from_json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Documento {
+    /// Versão do formato, sempre `VERSAO_DOCUMENTO` depois de carregado.
+    pub versao: u32,
+    #[serde(default)]
+    pub metadados: Metadados,
+    #[serde(default)]
+    pub semestre: Option<Semestre>,
+    pub disciplinas: Vec<Disciplina>,
+    #[serde(default)]
+    pub configuracoes: Configuracoes,
+    /// Edições feitas no cronograma, para desfazer e refazer depois de recarregar.
+    #[serde(default, skip_serializing_if = "Historico::is_empty")]
+    pub historico: Historico,
+}
+
+/// Informações descritivas de um `Documento`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Metadados {
+    /// Nome dado ao cronograma pelo usuário.
+    #[serde(default)]
+    pub titulo: String,
+    /// Momento da última alteração, se quem salvou souber informar.
+    #[serde(default)]
+    pub atualizado_em: Option<NaiveDateTime>,
+}
+
+/// Configurações do cronograma guardadas no `Documento`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Configuracoes {
+    #[serde(default)]
+    pub politica: OverlapPolicy,
+    #[serde(default)]
+    pub tabela: TabelaHorarios,
+    /// Dias e turnos da grade do cronograma.
+    #[serde(default, skip_serializing_if = "FormatoGrade::is_default")]
+    pub formato: FormatoGrade,
+    /// Abreviações fixadas pelo usuário, por nome de disciplina.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub abreviacoes: BTreeMap<String, String>,
+}
+
+/// Erros ao carregar um `Documento`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DocumentoError {
+    /// Conteúdo não é JSON válido ou não segue o formato da versão, com a mensagem do `serde_json`.
+    InvalidJson(String),
+    /// Documento salvo por uma versão mais nova do que esta biblioteca conhece.
+    UnsupportedVersion(u32),
+    /// JSON válido, mas que não corresponde a nenhum formato conhecido.
+    UnknownFormat,
+    /// As disciplinas do documento não cabem juntas no cronograma.
+    Schedule(ScheduleError),
+    /// `Schedule` antigo cuja grade não bate com as disciplinas que a ocupam no horário.
+    InconsistentGrid(SigaaTime),
+}
+
+/// Erros ao remontar um `Schedule` a partir de um `ScheduleData` ou da matriz das versões antigas.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ScheduleDataError {
+    /// As disciplinas não cabem juntas no cronograma, com o formato, a política e as abreviações salvos.
+    Schedule(ScheduleError),
+    /// Um horário da matriz antiga não tem as mesmas disciplinas que as disciplinas lidas dela ocupam nele.
+    InconsistentGrid(SigaaTime),
+}
 
 /// Erros que podem ocorrer ao trabalhar com cronogramas.
 ///
 /// Estes erros cobrem conflitos entre disciplinas, horários não encontrados e erros relacionados ao `SigaaTime`.
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ScheduleError {
-    /// Disciplina conflitante com outra disciplina.
-    ConflictingDisciplines(Disciplina, Disciplina),
+    /// Disciplina conflitante com outras disciplinas, com todos os horários em conflito.
+    ConflictingDisciplines(Vec<Conflict>),
     /// Horário não encontrado no cronograma.
     TimeNotFound(SigaaTime),
     /// Erros associados ao `SigaaTime`.
     SigaaTimeErrors(SigaaTimeErrors),
     /// Disciplina não encontrada para remoção
     DisciplineNotFoundToRemove,
+    /// Semestre termina antes de começar.
+    InvalidSemester,
+    /// Abreviação já usada por outra disciplina do cronograma.
+    AbbreviationInUse(String),
+    /// Abreviação vazia ou só com espaços.
+    EmptyAbbreviation,
 }
 
 /// Resultado de busca de disciplina.
@@ -47,5 +402,18 @@ pub enum DisciplineWasFound {
     DisciplineNotFound,
 }
 
+mod abreviacao;
+mod carga_horaria;
+mod diff;
+mod disponibilidade;
+mod documento;
+mod formato;
+mod generator;
+mod historico;
+pub mod ics;
+mod pontuacao;
 mod schedule;
 mod schedule_unity;
+mod semestre;
+mod serializacao;
+pub mod sigaa;
@@ -0,0 +1,152 @@
+use super::{Criterio, ItemPontuacao, Pesos, Pontuacao, Schedule};
+use class::Disciplina;
+use stf::{Dia, HorarioDiurno, SigaaTime, Turno};
+
+impl Default for Pesos {
+    fn default() -> Self {
+        Pesos { janela: 1.0, cedo: 1.0, sabado: 2.0, domingo: 2.0, noite: 1.0, dia_isolado: 1.0, dia_livre: 2.0 }
+    }
+}
+
+impl Pesos {
+    /// Peso de um critério.
+    pub fn peso(&self, criterio: Criterio) -> f64 {
+        match criterio {
+            Criterio::Janelas => self.janela,
+            Criterio::Cedo => self.cedo,
+            Criterio::Sabado => self.sabado,
+            Criterio::Domingo => self.domingo,
+            Criterio::Noite => self.noite,
+            Criterio::DiaIsolado => self.dia_isolado,
+            Criterio::DiaLivre => self.dia_livre,
+        }
+    }
+}
+
+impl Schedule {
+    /// Pontua o cronograma segundo os pesos informados.
+    ///
+    /// Cada horário da grade (ex: `2M12`) conta como uma ocorrência para janelas, `M12`, sábado, domingo e noite;
+    /// dias isolados e dias livres contam uma vez por dia.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::{Criterio, Pesos, Schedule};
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Cálculo I", "2M12").unwrap()).unwrap();
+    /// schedule.insert(Disciplina::new_stringify("Física I", "2T12").unwrap()).unwrap();
+    ///
+    /// let pontuacao = schedule.score(&Pesos::default());
+    /// let janelas = pontuacao.criterios.iter().find(|item| item.criterio == Criterio::Janelas).unwrap();
+    ///
+    /// assert_eq!(janelas.ocorrencias, 2);
+    /// assert_eq!(pontuacao.total, -2.0 - 1.0 + 5.0 * 2.0);
+    /// ```
+    pub fn score(&self, pesos: &Pesos) -> Pontuacao {
+        let (mut janelas, mut cedo, mut sabado, mut domingo, mut noite, mut dias_isolados, mut dias_livres) = (0, 0, 0, 0, 0, 0, 0);
+
+        // Posição do turno na grade, para que turnos fora do formato não contem como janela.
+        let linha = |sigaa_time: &SigaaTime| {
+            self.formato
+                .posicao(sigaa_time)
+                .map_or(0, |(linha, _)| linha)
+        };
+
+        for &dia in self.formato.dias() {
+            let ocupados: Vec<SigaaTime> = self
+                .formato
+                .turnos()
+                .iter()
+                .map(|&turno| SigaaTime::new(dia, turno))
+                .filter(|sigaa_time| {
+                    self.get(sigaa_time)
+                        .is_some_and(|unity| unity.disciplina.is_some())
+                })
+                .collect();
+
+            let (primeiro, ultimo) = match (ocupados.first(), ocupados.last()) {
+                (Some(primeiro), Some(ultimo)) => (linha(primeiro), linha(ultimo)),
+                _ => {
+                    dias_livres += 1;
+                    continue;
+                }
+            };
+
+            janelas += (ultimo - primeiro + 1 - ocupados.len()) as u32;
+            cedo += ocupados
+                .iter()
+                .filter(|sigaa_time| sigaa_time.turno == Turno::Manhã(HorarioDiurno::Primeiro))
+                .count() as u32;
+            noite += ocupados
+                .iter()
+                .filter(|sigaa_time| matches!(sigaa_time.turno, Turno::Noite(_)))
+                .count() as u32;
+
+            match dia {
+                Dia::Sabado => sabado += ocupados.len() as u32,
+                Dia::Domingo => domingo += ocupados.len() as u32,
+                _ => {}
+            }
+
+            let mut disciplinas: Vec<&Disciplina> = Vec::new();
+            for disciplina in ocupados.iter().flat_map(|sigaa_time| {
+                self.get(sigaa_time)
+                    .into_iter()
+                    .flat_map(|unity| unity.disciplinas())
+            }) {
+                if !disciplinas.contains(&disciplina) {
+                    disciplinas.push(disciplina);
+                }
+            }
+
+            if disciplinas.len() == 1 {
+                dias_isolados += 1;
+            }
+        }
+
+        let contagem = [(Criterio::Janelas, janelas), (Criterio::Cedo, cedo), (Criterio::Sabado, sabado), (Criterio::Domingo, domingo), (Criterio::Noite, noite), (Criterio::DiaIsolado, dias_isolados), (Criterio::DiaLivre, dias_livres)];
+
+        let criterios: Vec<ItemPontuacao> = contagem
+            .into_iter()
+            .map(|(criterio, ocorrencias)| {
+                let sinal = match criterio {
+                    Criterio::DiaLivre => 1.0,
+                    _ => -1.0,
+                };
+
+                ItemPontuacao { criterio, ocorrencias, pontos: sinal * pesos.peso(criterio) * ocorrencias as f64 }
+            })
+            .collect();
+
+        Pontuacao { total: criterios.iter().map(|item| item.pontos).sum(), criterios }
+    }
+
+    /// Ordena os cronogramas da maior para a menor pontuação e devolve os `n` primeiros.
+    ///
+    /// Cronogramas empatados mantêm a ordem em que foram informados.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use schedule::{Oferta, Pesos, Schedule};
+    ///
+    /// let ofertas = [Oferta::new("Cálculo I", &["2M12", "2M34"]).unwrap()];
+    /// let candidatos = Schedule::generate(&ofertas);
+    /// let ranking = Schedule::rank(&candidatos, &Pesos::default(), 1);
+    ///
+    /// assert!(ranking[0].0 == &candidatos[1]);
+    /// ```
+    pub fn rank<'a>(candidatos: &'a [Schedule], pesos: &Pesos, n: usize) -> Vec<(&'a Schedule, Pontuacao)> {
+        let mut ranking: Vec<(&Schedule, Pontuacao)> = candidatos
+            .iter()
+            .map(|schedule| (schedule, schedule.score(pesos)))
+            .collect();
+
+        ranking.sort_by(|(_, a), (_, b)| b.total.total_cmp(&a.total));
+        ranking.truncate(n);
+        ranking
+    }
+}
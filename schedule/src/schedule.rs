@@ -1,12 +1,13 @@
-use super::{DisciplineWasFound, DisciplineWasFound::*, Schedule, ScheduleError, ScheduleUnity};
-use class::Disciplina;
-use stf::{Dia, SigaaTime, Turno};
+use super::{Conflict, DisciplineWasFound, DisciplineWasFound::*, FormatoGrade, OverlapPolicy, Schedule, ScheduleError, ScheduleUnity};
+use class::{Atividade, Disciplina};
+use std::collections::BTreeMap;
+use stf::{Dia, SigaaTime, SigaaTimeSet};
 
 impl Schedule {
     /// Cria uma nova instância de `Schedule` com uma matriz de `ScheduleUnity` inicializada.
     ///
-    /// A matriz tem 8 linhas e 6 colunas, representando diferentes turnos e dias da semana.
-    /// Cada `ScheduleUnity` é inicializado com um horário (`SigaaTime`) e nenhuma disciplina.
+    /// A matriz segue o `FormatoGrade` padrão: uma linha por turno e uma coluna para cada dia de segunda
+    /// a sábado. Cada `ScheduleUnity` é inicializado com um horário (`SigaaTime`) e nenhuma disciplina.
     ///
     /// # Exemplo
     ///
@@ -15,13 +16,64 @@ impl Schedule {
     /// let schedule = Schedule::new();
     /// ```
     pub fn new() -> Schedule {
-        Schedule((0..8).map(create_row).collect())
+        Schedule::with_policy(OverlapPolicy::default())
+    }
+
+    /// Cria um cronograma vazio com a política de sobreposição informada.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::{OverlapPolicy, Schedule};
+    ///
+    /// let mut schedule = Schedule::with_policy(OverlapPolicy::Allow);
+    /// schedule.insert(Disciplina::new_stringify("Física", "2M12").unwrap()).unwrap();
+    /// schedule.insert(Disciplina::new_stringify("Física - Laboratório", "2M12").unwrap()).unwrap();
+    ///
+    /// assert_eq!(schedule.overlaps().len(), 1);
+    /// ```
+    pub fn with_policy(politica: OverlapPolicy) -> Schedule {
+        Schedule { politica, ..Schedule::with_format(FormatoGrade::default()) }
+    }
+
+    /// Cria um cronograma vazio com os dias e turnos do formato informado.
+    pub fn with_format(formato: FormatoGrade) -> Schedule {
+        let grade = formato
+            .turnos()
+            .iter()
+            .map(|&turno| {
+                formato
+                    .dias()
+                    .iter()
+                    .map(|&dia| ScheduleUnity::new(SigaaTime::new(dia, turno), None))
+                    .collect()
+            })
+            .collect();
+
+        Schedule { grade, formato, politica: OverlapPolicy::default(), fixadas: BTreeMap::new(), ocupados: SigaaTimeSet::new() }
+    }
+
+    /// Dias e turnos da grade do cronograma.
+    pub fn format(&self) -> &FormatoGrade {
+        &self.formato
+    }
+
+    /// Política de sobreposição usada nas inserções.
+    pub fn policy(&self) -> OverlapPolicy {
+        self.politica
+    }
+
+    /// Altera a política de sobreposição. Sobreposições já existentes são mantidas.
+    pub fn set_policy(&mut self, politica: OverlapPolicy) {
+        self.politica = politica
     }
 
     /// Insere uma disciplina no cronograma.
     ///
     /// Se a disciplina não estiver ocupando nenhum horário existente, ela será inserida nos horários correspondentes.
-    /// Se houver conflitos com disciplinas existentes, retorna um erro.
+    /// Se houver conflitos com disciplinas existentes, o resultado depende da `OverlapPolicy` do cronograma: com
+    /// `OverlapPolicy::Reject` nada é alterado e retorna um erro com todos os conflitos.
     ///
     /// # Parâmetros
     ///
@@ -31,28 +83,86 @@ impl Schedule {
     ///
     /// Retorna `Ok(())` se a inserção for bem-sucedida, ou um erro do tipo `ScheduleError` se houver conflitos ou problemas.
     pub fn insert(&mut self, disciplina: Disciplina) -> Result<(), ScheduleError> {
-        match self.verify_availability(&disciplina) {
-            DisciplineNotFound => self.update_discipline(disciplina.clone(), Some(disciplina)),
-            DisciplineFound(found_discipline) => Err(ScheduleError::ConflictingDisciplines(found_discipline, disciplina)),
+        self.insert_with_warnings(disciplina).map(|_| ())
+    }
+
+    /// Insere uma disciplina e devolve os conflitos aceitos como aviso.
+    ///
+    /// Com `OverlapPolicy::Warn`, a disciplina é inserida mesmo com conflitos e eles são retornados; com
+    /// `OverlapPolicy::Allow` é inserida e a lista volta vazia. Inserir uma disciplina que já está no
+    /// cronograma é sempre recusado, assim como uma disciplina com horários fora do `FormatoGrade`.
+    ///
+    /// A abreviação da disciplina pode ser trocada para não repetir a de outra disciplina do cronograma
+    /// (veja `Schedule::pin_abbreviation`).
+    pub fn insert_with_warnings(&mut self, mut disciplina: Disciplina) -> Result<Vec<Conflict>, ScheduleError> {
+        if let Some(&fora) = disciplina
+            .sigaa_time
+            .iter()
+            .find(|sigaa_time| !self.formato.contains(sigaa_time))
+        {
+            return Err(ScheduleError::TimeNotFound(fora));
+        }
+
+        self.allocate_abbreviation(&mut disciplina);
+
+        let conflicts = self.conflicts(&disciplina);
+        let repetida = conflicts
+            .iter()
+            .any(|conflict| conflict.disciplina == disciplina);
+
+        match (self.politica, conflicts.is_empty()) {
+            (_, false) if repetida => Err(ScheduleError::ConflictingDisciplines(conflicts)),
+            (OverlapPolicy::Reject, false) => Err(ScheduleError::ConflictingDisciplines(conflicts)),
+            (OverlapPolicy::Warn, _) => self
+                .update_discipline(&disciplina, true)
+                .map(|_| conflicts),
+            _ => self
+                .update_discipline(&disciplina, true)
+                .map(|_| vec![]),
         }
     }
 
+    /// Remove uma disciplina do cronograma.
+    ///
+    /// Todos os horários da disciplina precisam estar ocupados por ela. Se algum estiver ocupado por outra
+    /// disciplina, retorna os conflitos; se a disciplina não estiver no cronograma, retorna
+    /// `ScheduleError::DisciplineNotFoundToRemove`.
     pub fn remove(&mut self, disciplina: Disciplina) -> Result<(), ScheduleError> {
-        match self.verify_availability(&disciplina) {
-            DisciplineFound(founded_discipline) => match founded_discipline == disciplina {
-                true => self.update_discipline(disciplina, None),
-                false => Err(ScheduleError::ConflictingDisciplines(founded_discipline, disciplina)),
-            },
-            DisciplineNotFound => Err(ScheduleError::DisciplineNotFoundToRemove),
+        let presente = disciplina.sigaa_time.iter().all(|sigaa_time| {
+            self.get(sigaa_time)
+                .is_some_and(|unity| unity.contains(&disciplina))
+        });
+
+        let conflicts: Vec<Conflict> = self
+            .conflicts(&disciplina)
+            .into_iter()
+            .filter(|conflict| conflict.disciplina != disciplina)
+            .collect();
+
+        match (presente, conflicts.is_empty()) {
+            (true, _) => self.update_discipline(&disciplina, false),
+            (false, false) => Err(ScheduleError::ConflictingDisciplines(conflicts)),
+            (false, true) => Err(ScheduleError::DisciplineNotFoundToRemove),
         }
     }
 
-    fn update_discipline(&mut self, disciplina: Disciplina, insert_discipline: Option<Disciplina>) -> Result<(), ScheduleError> {
+    fn update_discipline(&mut self, disciplina: &Disciplina, inserir: bool) -> Result<(), ScheduleError> {
         for &sigaa_time in &disciplina.sigaa_time {
-            match self.get_mut(sigaa_time) {
-                Some(schedule_unity) => schedule_unity.update(insert_discipline.clone()),
-                None => return Err(ScheduleError::TimeNotFound(sigaa_time)),
+            let schedule_unity = self
+                .get_mut(sigaa_time)
+                .ok_or(ScheduleError::TimeNotFound(sigaa_time))?;
+
+            match inserir {
+                true => schedule_unity.push(disciplina.clone()),
+                false => schedule_unity.remove(disciplina),
             }
+
+            let ocupado = schedule_unity.disciplinas().next().is_some();
+
+            match ocupado {
+                true => self.ocupados.insert(sigaa_time),
+                false => self.ocupados.remove(&sigaa_time),
+            };
         }
 
         Ok(())
@@ -66,50 +176,168 @@ impl Schedule {
     ///
     /// # Retorno
     ///
-    /// Retorna `DisciplineWasFound::DisciplineFound` se uma disciplina já estiver ocupando algum dos horários
-    /// da disciplina fornecida, caso contrário, retorna `DisciplineWasFound::DisciplineNotFound`.
+    /// Retorna `DisciplineWasFound::DisciplineFound` com a primeira disciplina que estiver ocupando algum dos horários
+    /// da disciplina fornecida, caso contrário, retorna `DisciplineWasFound::DisciplineNotFound`. Para a lista
+    /// completa de conflitos, use `Schedule::conflicts`.
     pub fn verify_availability(&self, disciplina: &Disciplina) -> DisciplineWasFound {
-        if let Some(sigaa_time) = &disciplina.sigaa_time.first() {
-            match &self.get(sigaa_time).disciplina {
-                Some(found_discipline) => return DisciplineFound(found_discipline.clone()),
-                None => return DisciplineNotFound,
-            };
+        match self.conflicts(disciplina).into_iter().next() {
+            Some(conflict) => DisciplineFound(conflict.disciplina),
+            None => DisciplineNotFound,
+        }
+    }
+
+    /// Lista todos os horários da disciplina que já estão ocupados no cronograma.
+    ///
+    /// Cada `Conflict` traz o horário disputado e a disciplina que o ocupa, em ordem de horário.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::Schedule;
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Física", "4M12").unwrap()).unwrap();
+    ///
+    /// let calculo = Disciplina::new_stringify("Cálculo", "246M12").unwrap();
+    /// let conflicts = schedule.conflicts(&calculo);
+    ///
+    /// assert_eq!(conflicts.len(), 1);
+    /// assert_eq!(conflicts[0].horario.to_string(), "4M12");
+    /// assert_eq!(conflicts[0].disciplina.nome, "Física");
+    /// ```
+    pub fn conflicts(&self, disciplina: &Disciplina) -> Vec<Conflict> {
+        let disputados = SigaaTimeSet::from(&disciplina.sigaa_time) & self.ocupados;
+
+        disputados
+            .iter()
+            .flat_map(|sigaa_time| {
+                self.get(&sigaa_time)
+                    .into_iter()
+                    .flat_map(|unity| unity.disciplinas())
+                    .map(move |ocupante| Conflict { horario: sigaa_time, disciplina: ocupante.clone() })
+            })
+            .collect()
+    }
+
+    /// Verifica se algum horário da disciplina já está ocupado, sem percorrer a grade nem montar os conflitos.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::Schedule;
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Física", "4M12").unwrap()).unwrap();
+    ///
+    /// assert!(schedule.has_conflicts(&Disciplina::new_stringify("Cálculo", "246M12").unwrap()));
+    /// assert!(!schedule.has_conflicts(&Disciplina::new_stringify("Cálculo", "35M12").unwrap()));
+    /// assert_eq!(schedule.occupied_times().to_string(), "4M12");
+    /// ```
+    pub fn has_conflicts(&self, disciplina: &Disciplina) -> bool {
+        !SigaaTimeSet::from(&disciplina.sigaa_time).is_disjoint(&self.ocupados)
+    }
+
+    /// Horários ocupados por alguma disciplina.
+    pub fn occupied_times(&self) -> SigaaTimeSet {
+        self.ocupados
+    }
+
+    /// Unidades de horário ocupadas por mais de uma disciplina, em ordem de turno e dia.
+    pub fn overlaps(&self) -> Vec<&ScheduleUnity> {
+        self.grade
+            .iter()
+            .flatten()
+            .filter(|unity| unity.is_overlapped())
+            .collect()
+    }
+
+    /// Horários com a atividade informada, em ordem de turno e dia, com a disciplina de cada um.
+    ///
+    /// Disciplinas sobrepostas aparecem todas, cada uma com a atividade do seu próprio horário.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::{Atividade, Disciplina};
+    /// use schedule::Schedule;
+    /// use stf::SigaaTime;
+    ///
+    /// let quinta = SigaaTime::try_from("5T34").unwrap();
+    /// let mut fisica = Disciplina::new_stringify("Física I", "2M12 5T34").unwrap();
+    /// fisica.set_atividade(quinta, Atividade::Laboratorio).unwrap();
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(fisica).unwrap();
+    ///
+    /// let laboratorios = schedule.by_activity(Atividade::Laboratorio);
+    /// assert_eq!(laboratorios.len(), 1);
+    /// assert_eq!(laboratorios[0].0, quinta);
+    /// ```
+    pub fn by_activity(&self, atividade: Atividade) -> Vec<(SigaaTime, &Disciplina)> {
+        self.grade
+            .iter()
+            .flatten()
+            .flat_map(|unity| {
+                unity
+                    .disciplinas()
+                    .map(|disciplina| (unity.horario, disciplina))
+            })
+            .filter(|(horario, disciplina)| disciplina.atividade_em(horario) == atividade)
+            .collect()
+    }
+
+    /// Locais visitados em cada dia, sem repetição, na ordem em que são visitados.
+    ///
+    /// Dias sem aula ou cujas disciplinas não têm local ficam de fora.
+    pub fn rooms_by_day(&self) -> BTreeMap<Dia, Vec<&str>> {
+        let mut locais: BTreeMap<Dia, Vec<&str>> = BTreeMap::new();
+
+        for unity in self.grade.iter().flatten() {
+            for local in unity
+                .disciplinas()
+                .filter_map(|disciplina| disciplina.local_em(&unity.horario))
+            {
+                let do_dia = locais.entry(unity.horario.dia).or_default();
+                if !do_dia.contains(&local) {
+                    do_dia.push(local);
+                }
+            }
         }
 
-        DisciplineNotFound
+        locais
     }
 
     /// Obtém uma referência para um `ScheduleUnity` específico.
     ///
     /// # Parâmetros
     ///
-    /// * `row` - O índice da linha (turno) na matriz.
-    /// * `col` - O índice da coluna (dia) na matriz.
+    /// * `sigaa_time` - O horário cuja célula será buscada.
     ///
     /// # Retorno
     ///
-    /// Retorna uma referência para o `ScheduleUnity` se o índice for válido, caso contrário, retorna `None`.
-    pub fn get(&self, sigaa_time: &SigaaTime) -> &ScheduleUnity {
-        let row: usize = sigaa_time.turno.into();
-        let col: usize = sigaa_time.dia.into();
-        self.0.get(row).unwrap().get(col).unwrap()
+    /// Retorna uma referência para o `ScheduleUnity` do horário, ou `None` se o horário estiver fora do
+    /// `FormatoGrade` do cronograma.
+    pub fn get(&self, sigaa_time: &SigaaTime) -> Option<&ScheduleUnity> {
+        let (row, col) = self.formato.posicao(sigaa_time)?;
+        self.grade.get(row)?.get(col)
     }
 
     /// Obtém uma referência mutável para um `ScheduleUnity` específico.
     ///
     /// # Parâmetros
     ///
-    /// * `turno_index` - O índice do turno (linha) na matriz.
-    /// * `dia_index` - O índice do dia (coluna) na matriz.
+    /// * `sigaa_time` - O horário cuja célula será buscada.
     ///
     /// # Retorno
     ///
-    /// Retorna uma referência mutável para o `ScheduleUnity` se o índice for válido, caso contrário, retorna `None`.
+    /// Retorna uma referência mutável para o `ScheduleUnity` do horário, ou `None` se o horário estiver fora do
+    /// `FormatoGrade` do cronograma.
     fn get_mut(&mut self, sigaa_time: SigaaTime) -> Option<&mut ScheduleUnity> {
-        let turno_index: usize = sigaa_time.turno.into();
-        let dia_index: usize = sigaa_time.dia.into();
+        let (turno_index, dia_index) = self.formato.posicao(&sigaa_time)?;
 
-        self.0
+        self.grade
             .get_mut(turno_index)
             .and_then(|row| row.get_mut(dia_index))
     }
@@ -125,32 +353,101 @@ impl Schedule {
     ///
     /// Retorna uma referência para o `ScheduleUnity` se a for válido, caso contrário, retorna `None`.
     pub fn get_from_str(&self, input: &str) -> Option<&ScheduleUnity> {
-        let sigaa_time_str: SigaaTime = input.try_into().unwrap();
+        let sigaa_time_str: SigaaTime = input.try_into().ok()?;
+
+        self.get(&sigaa_time_str)
+    }
+
+    /// Disciplinas presentes no cronograma, sem repetição, na ordem em que aparecem na grade.
+    ///
+    /// A lista é lida da própria grade, então sempre corresponde aos horários ocupados; disciplinas
+    /// sobrepostas aparecem uma vez cada.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::Schedule;
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Física I", "35T34").unwrap()).unwrap();
+    /// schedule.insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap()).unwrap();
+    ///
+    /// let nomes: Vec<&str> = schedule.disciplines().iter().map(|d| d.nome.as_str()).collect();
+    /// assert_eq!(nomes, ["Cálculo I", "Física I"]);
+    /// assert_eq!(schedule.discipline_count(), 2);
+    /// ```
+    pub fn disciplines(&self) -> Vec<&Disciplina> {
+        let mut disciplinas: Vec<&Disciplina> = Vec::new();
 
-        let (turno, dia): (usize, usize) = (sigaa_time_str.turno.into(), sigaa_time_str.dia.into());
+        for disciplina in self
+            .grade
+            .iter()
+            .flatten()
+            .flat_map(|unity| unity.disciplinas())
+        {
+            if !disciplinas.contains(&disciplina) {
+                disciplinas.push(disciplina);
+            }
+        }
 
-        self.0.get(turno)?.get(dia)
+        disciplinas
     }
-}
 
-impl Default for Schedule {
-    fn default() -> Self {
-        Self::new()
+    /// Quantidade de disciplinas distintas no cronograma.
+    pub fn discipline_count(&self) -> usize {
+        self.disciplines().len()
     }
-}
 
-fn create_row(row: usize) -> Vec<ScheduleUnity> {
-    (0..6)
-        .map(|col| (row, col))
-        .map(usize_to_turno_dia)
-        .map(create_schedule_unity)
-        .collect()
-}
+    /// Verifica se a disciplina está no cronograma, com os mesmos horários e dados de turma.
+    pub fn contains_discipline(&self, disciplina: &Disciplina) -> bool {
+        disciplina
+            .sigaa_time
+            .first()
+            .and_then(|sigaa_time| self.get(sigaa_time))
+            .is_some_and(|unity| unity.contains(disciplina))
+    }
+
+    /// Disciplinas com o nome informado, ex: as turmas de um mesmo componente em horários diferentes.
+    pub fn find_by_name(&self, nome: &str) -> Vec<&Disciplina> {
+        self.disciplines()
+            .into_iter()
+            .filter(|disciplina| disciplina.nome == nome)
+            .collect()
+    }
 
-fn create_schedule_unity((row, col): (Turno, Dia)) -> ScheduleUnity {
-    ScheduleUnity::new(SigaaTime::new(col, row), None)
+    /// Disciplinas com o código de componente informado (ex: `DIM0120`), sem diferenciar maiúsculas.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::Schedule;
+    ///
+    /// let calculo = Disciplina { codigo: Some("DIM0120".to_string()), ..Disciplina::new_stringify("Cálculo I", "24M12").unwrap() };
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(calculo.clone()).unwrap();
+    ///
+    /// assert_eq!(schedule.find_by_code("dim0120"), [&calculo]);
+    /// assert_eq!(schedule.find_by_name("Cálculo I"), [&calculo]);
+    /// assert!(schedule.find_by_code("DIM0121").is_empty());
+    /// ```
+    pub fn find_by_code(&self, codigo: &str) -> Vec<&Disciplina> {
+        self.disciplines()
+            .into_iter()
+            .filter(|disciplina| {
+                disciplina
+                    .codigo
+                    .as_deref()
+                    .is_some_and(|proprio| proprio.eq_ignore_ascii_case(codigo))
+            })
+            .collect()
+    }
 }
 
-fn usize_to_turno_dia((row, col): (usize, usize)) -> (Turno, Dia) {
-    (row.try_into().unwrap(), col.try_into().unwrap())
+impl Default for Schedule {
+    fn default() -> Self {
+        Self::new()
+    }
 }
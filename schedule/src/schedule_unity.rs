@@ -1,5 +1,5 @@
 use super::ScheduleUnity;
-use class::Disciplina;
+use class::{Atividade, Disciplina};
 use stf::SigaaTime;
 
 impl ScheduleUnity {
@@ -17,10 +17,64 @@ impl ScheduleUnity {
         ScheduleUnity {
             horario,
             disciplina,
+            sobrepostas: vec![],
         }
     }
 
     pub fn update(&mut self, disciplina: Option<Disciplina>) {
         self.disciplina = disciplina
     }
+
+    /// Todas as disciplinas deste horário, começando pela principal.
+    pub fn disciplinas(&self) -> impl Iterator<Item = &Disciplina> {
+        self.disciplina
+            .iter()
+            .chain(self.sobrepostas.iter())
+    }
+
+    /// Verifica se a disciplina ocupa este horário, como principal ou sobreposta.
+    pub fn contains(&self, disciplina: &Disciplina) -> bool {
+        self.disciplinas()
+            .any(|ocupante| ocupante == disciplina)
+    }
+
+    /// Local da disciplina principal neste horário.
+    pub fn local(&self) -> Option<&str> {
+        self.disciplina
+            .as_ref()
+            .and_then(|disciplina| disciplina.local_em(&self.horario))
+    }
+
+    /// Tipo de atividade da disciplina principal neste horário, se houver disciplina.
+    pub fn atividade(&self) -> Option<Atividade> {
+        self.disciplina
+            .as_ref()
+            .map(|disciplina| disciplina.atividade_em(&self.horario))
+    }
+
+    /// Indica se mais de uma disciplina ocupa este horário.
+    pub fn is_overlapped(&self) -> bool {
+        !self.sobrepostas.is_empty()
+    }
+
+    /// Adiciona uma disciplina ao horário; se já houver uma principal, ela fica como sobreposta.
+    pub fn push(&mut self, disciplina: Disciplina) {
+        match self.disciplina {
+            None => self.disciplina = Some(disciplina),
+            Some(_) => self.sobrepostas.push(disciplina),
+        }
+    }
+
+    /// Retira a disciplina do horário. Se ela era a principal, a primeira sobreposta assume o lugar.
+    pub fn remove(&mut self, disciplina: &Disciplina) {
+        if self.disciplina.as_ref() == Some(disciplina) {
+            self.disciplina = match self.sobrepostas.is_empty() {
+                true => None,
+                false => Some(self.sobrepostas.remove(0)),
+            };
+        } else {
+            self.sobrepostas
+                .retain(|ocupante| ocupante != disciplina);
+        }
+    }
 }
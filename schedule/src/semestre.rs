@@ -0,0 +1,80 @@
+use super::{ScheduleError, Semestre};
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::BTreeSet;
+use stf::Dia;
+
+impl Semestre {
+    /// Cria um novo semestre sem feriados.
+    ///
+    /// Retorna `ScheduleError::InvalidSemester` se `fim` for anterior a `inicio`.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use schedule::Semestre;
+    /// let inicio = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    /// let fim = NaiveDate::from_ymd_opt(2025, 7, 12).unwrap();
+    /// let semestre = Semestre::new(inicio, fim).unwrap();
+    /// ```
+    pub fn new(inicio: NaiveDate, fim: NaiveDate) -> Result<Semestre, ScheduleError> {
+        match inicio <= fim {
+            true => Ok(Semestre { inicio, fim, feriados: BTreeSet::new() }),
+            false => Err(ScheduleError::InvalidSemester),
+        }
+    }
+
+    /// Adiciona um feriado ao semestre. Datas fora do semestre são ignoradas.
+    pub fn add_feriado(&mut self, data: NaiveDate) {
+        if self.contains(data) {
+            self.feriados.insert(data);
+        }
+    }
+
+    /// Verifica se a data está dentro do semestre.
+    pub fn contains(&self, data: NaiveDate) -> bool {
+        self.inicio <= data && data <= self.fim
+    }
+
+    /// Primeira data do semestre que cai no dia da semana informado.
+    pub fn primeiro(&self, dia: Dia) -> Option<NaiveDate> {
+        self.inicio
+            .iter_days()
+            .take(7)
+            .find(|data| data.weekday() == to_weekday(dia))
+            .filter(|data| self.contains(*data))
+    }
+
+    /// Feriados do semestre que caem no dia da semana informado.
+    pub fn feriados_em(&self, dia: Dia) -> impl Iterator<Item = &NaiveDate> {
+        self.feriados
+            .iter()
+            .filter(move |data| data.weekday() == to_weekday(dia))
+    }
+}
+
+/// Converte um `Dia` do SIGAA para o dia da semana do `chrono`.
+pub(crate) fn to_weekday(dia: Dia) -> Weekday {
+    match dia {
+        Dia::Segunda => Weekday::Mon,
+        Dia::Terça => Weekday::Tue,
+        Dia::Quarta => Weekday::Wed,
+        Dia::Quinta => Weekday::Thu,
+        Dia::Sexta => Weekday::Fri,
+        Dia::Sabado => Weekday::Sat,
+        Dia::Domingo => Weekday::Sun,
+    }
+}
+
+/// Converte um dia da semana do `chrono` para um `Dia` do SIGAA.
+pub(crate) fn from_weekday(weekday: Weekday) -> Dia {
+    match weekday {
+        Weekday::Mon => Dia::Segunda,
+        Weekday::Tue => Dia::Terça,
+        Weekday::Wed => Dia::Quarta,
+        Weekday::Thu => Dia::Quinta,
+        Weekday::Fri => Dia::Sexta,
+        Weekday::Sat => Dia::Sabado,
+        Weekday::Sun => Dia::Domingo,
+    }
+}
@@ -0,0 +1,128 @@
+use super::{Schedule, ScheduleData, ScheduleDataError, ScheduleSalvo, ScheduleUnity};
+use class::Disciplina;
+use std::fmt::Display;
+
+impl From<Schedule> for ScheduleData {
+    fn from(schedule: Schedule) -> Self {
+        let disciplinas = schedule
+            .disciplines()
+            .into_iter()
+            .cloned()
+            .collect();
+
+        ScheduleData { disciplinas, formato: schedule.formato, politica: schedule.politica, fixadas: schedule.fixadas }
+    }
+}
+
+impl TryFrom<ScheduleData> for Schedule {
+    type Error = ScheduleDataError;
+
+    /// Remonta a grade com as disciplinas salvas, recusando conflitos que a política não permite, horários fora
+    /// do formato e abreviações fixadas repetidas.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use class::Disciplina;
+    /// use schedule::{Schedule, ScheduleData, ScheduleDataError, ScheduleError};
+    ///
+    /// let mut schedule = Schedule::new();
+    /// schedule.insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap()).unwrap();
+    ///
+    /// let mut dados = ScheduleData::from(schedule.clone());
+    /// assert!(Schedule::try_from(dados.clone()).unwrap() == schedule);
+    ///
+    /// dados.disciplinas.push(Disciplina::new_stringify("Física I", "4M12").unwrap());
+    /// assert!(matches!(Schedule::try_from(dados), Err(ScheduleDataError::Schedule(ScheduleError::ConflictingDisciplines(_)))));
+    /// ```
+    fn try_from(dados: ScheduleData) -> Result<Self, Self::Error> {
+        let mut schedule = Schedule::with_format(dados.formato);
+        schedule.set_policy(dados.politica);
+
+        for (nome, abreviacao) in &dados.fixadas {
+            schedule
+                .pin_abbreviation(nome, abreviacao)
+                .map_err(ScheduleDataError::Schedule)?;
+        }
+
+        for disciplina in dados.disciplinas {
+            schedule
+                .insert(disciplina)
+                .map_err(ScheduleDataError::Schedule)?;
+        }
+
+        Ok(schedule)
+    }
+}
+
+impl TryFrom<Vec<Vec<ScheduleUnity>>> for Schedule {
+    type Error = ScheduleDataError;
+
+    /// Remonta um cronograma salvo como a matriz inteira pelas versões antigas.
+    ///
+    /// As disciplinas são inseridas na ordem em que aparecem na grade, no formato padrão e recusando
+    /// conflitos, como era o único cronograma possível; depois, cada unidade salva precisa bater com a remontada.
+    fn try_from(grade: Vec<Vec<ScheduleUnity>>) -> Result<Self, Self::Error> {
+        let grade: Vec<ScheduleUnity> = grade.into_iter().flatten().collect();
+        let mut schedule = Schedule::new();
+
+        for disciplina in grade.iter().flat_map(ScheduleUnity::disciplinas) {
+            if !schedule.contains_discipline(disciplina) {
+                schedule
+                    .insert(disciplina.clone())
+                    .map_err(ScheduleDataError::Schedule)?;
+            }
+        }
+
+        conferir(schedule, &grade)
+    }
+}
+
+impl TryFrom<ScheduleSalvo> for Schedule {
+    type Error = ScheduleDataError;
+
+    fn try_from(salvo: ScheduleSalvo) -> Result<Self, Self::Error> {
+        match salvo {
+            ScheduleSalvo::Dados(dados) => Schedule::try_from(dados),
+            ScheduleSalvo::Grade(grade) => Schedule::try_from(grade),
+        }
+    }
+}
+
+impl Display for ScheduleDataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleDataError::Schedule(erro) => write!(f, "as disciplinas não formam um cronograma válido: {erro:?}"),
+            ScheduleDataError::InconsistentGrid(horario) => write!(f, "a grade salva não bate com as disciplinas no horário {horario}"),
+        }
+    }
+}
+
+impl std::error::Error for ScheduleDataError {}
+
+/// Devolve o cronograma remontado se cada unidade da grade salva tiver as mesmas disciplinas que ele.
+fn conferir(schedule: Schedule, grade: &[ScheduleUnity]) -> Result<Schedule, ScheduleDataError> {
+    match grade
+        .iter()
+        .find(|unity| !mesmas_disciplinas(schedule.get(&unity.horario), unity))
+    {
+        Some(unity) => Err(ScheduleDataError::InconsistentGrid(unity.horario)),
+        None => Ok(schedule),
+    }
+}
+
+/// Verifica se a unidade remontada tem as mesmas disciplinas da unidade salva, em qualquer ordem: a principal
+/// de cada horário depende da ordem de inserção, que a grade antiga não guarda.
+fn mesmas_disciplinas(remontada: Option<&ScheduleUnity>, salva: &ScheduleUnity) -> bool {
+    let Some(remontada) = remontada else {
+        return false;
+    };
+
+    let remontadas: Vec<&Disciplina> = remontada.disciplinas().collect();
+    let salvas: Vec<&Disciplina> = salva.disciplinas().collect();
+
+    remontadas.len() == salvas.len()
+        && salvas
+            .iter()
+            .all(|disciplina| remontadas.contains(disciplina))
+}
@@ -0,0 +1,411 @@
+//! Leitura das turmas de uma página do SIGAA (atestado de matrícula ou lista de turmas).
+//!
+//! Aceita tanto o HTML salvo da página quanto o texto copiado da tabela. As colunas são
+//! encontradas pelo cabeçalho (`Código`, `Componente Curricular`, `Turma`, `Local`, `Horário`),
+//! então a ordem delas e colunas extras (como `Status`) não importam.
+
+use class::Disciplina;
+use stf::{ParseError, SigaaTime};
+
+/// Uma turma lida da página do SIGAA.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matricula {
+    /// Código do componente, ex: `DIM0119`.
+    pub codigo: String,
+    /// Nome do componente, como aparece no SIGAA.
+    pub nome: String,
+    /// Código da turma, ex: `01`.
+    pub turma: String,
+    /// Sala ou local das aulas.
+    pub local: String,
+    /// Código de horário, ex: `35T34`.
+    pub horario: String,
+    /// Disciplina montada com o nome, o horário e os dados da turma.
+    pub disciplina: Disciplina,
+}
+
+/// Resultado da leitura de uma página do SIGAA.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SigaaImport {
+    /// Turmas lidas, na ordem da página.
+    pub matriculas: Vec<Matricula>,
+    /// Linhas da tabela que pareciam turmas mas não puderam ser lidas.
+    pub rejeitadas: Vec<RejectedRow>,
+}
+
+/// Linha da tabela que ficou de fora da importação.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedRow {
+    /// Células da linha, já sem marcação HTML.
+    pub celulas: Vec<String>,
+    /// Por que a linha não foi importada.
+    pub motivo: RowRejection,
+}
+
+/// Motivos para uma linha não ser importada.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowRejection {
+    /// A coluna de horário está vazia ou não começa com um código de horário.
+    MissingTime,
+    /// O código de horário é inválido, com o erro do parser.
+    InvalidTime(ParseError),
+}
+
+/// Erros de leitura de uma página do SIGAA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SigaaImportError {
+    /// Nenhuma linha de cabeçalho com as colunas de componente e horário foi encontrada.
+    MissingHeader,
+}
+
+/// Posição de cada coluna conhecida numa tabela.
+#[derive(Default)]
+struct Colunas {
+    codigo: Option<usize>,
+    componente: Option<usize>,
+    turma: Option<usize>,
+    local: Option<usize>,
+    horario: usize,
+}
+
+/// Lê as turmas de uma página do SIGAA, em HTML ou em texto copiado.
+///
+/// # Exemplo
+///
+/// ```
+/// use schedule::sigaa;
+///
+/// let texto = "Código\tComponente Curricular\tTurma\tLocal\tHorário\n\
+///              DIM0119\tESTRUTURAS DE DADOS BÁSICAS I\t01\tA308\t35T34 (03/03/2025 - 12/07/2025)\n";
+///
+/// let importado = sigaa::parse(texto).unwrap();
+/// let matricula = &importado.matriculas[0];
+///
+/// assert_eq!(matricula.codigo, "DIM0119");
+/// assert_eq!(matricula.horario, "35T34");
+/// assert_eq!(matricula.disciplina.sigaa_time.len(), 2);
+/// ```
+pub fn parse(conteudo: &str) -> Result<SigaaImport, SigaaImportError> {
+    let minusculo = conteudo.to_ascii_lowercase();
+
+    match minusculo.contains("<td") || minusculo.contains("<th") {
+        true => parse_html(conteudo),
+        false => parse_text(conteudo),
+    }
+}
+
+/// Lê as turmas do HTML salvo de uma página do SIGAA.
+pub fn parse_html(html: &str) -> Result<SigaaImport, SigaaImportError> {
+    importar(linhas_html(html))
+}
+
+/// Lê as turmas do texto copiado de uma tabela do SIGAA.
+///
+/// As células são separadas por tabulação (como o navegador copia tabelas) ou, na falta dela, por
+/// dois ou mais espaços.
+pub fn parse_text(texto: &str) -> Result<SigaaImport, SigaaImportError> {
+    importar(texto.lines().map(celulas_texto).collect())
+}
+
+fn importar(linhas: Vec<Vec<String>>) -> Result<SigaaImport, SigaaImportError> {
+    let mut colunas: Option<Colunas> = None;
+    let mut importado = SigaaImport { matriculas: Vec::new(), rejeitadas: Vec::new() };
+
+    for celulas in linhas {
+        if let Some(cabecalho) = cabecalho(&celulas) {
+            colunas = Some(cabecalho);
+            continue;
+        }
+
+        let Some(colunas) = &colunas else { continue };
+        let celula = |indice: Option<usize>| {
+            indice
+                .and_then(|indice| celulas.get(indice))
+                .cloned()
+                .unwrap_or_default()
+        };
+
+        let (Some(horario), Some(componente)) = (
+            celulas.get(colunas.horario),
+            colunas
+                .componente
+                .or(colunas.codigo)
+                .and_then(|indice| celulas.get(indice)),
+        ) else {
+            continue;
+        };
+
+        if componente.is_empty() {
+            continue;
+        }
+
+        let (codigo, nome) = match (colunas.codigo, colunas.componente) {
+            (Some(_), Some(_)) => (celula(colunas.codigo), celula(colunas.componente)),
+            (Some(_), None) => (celula(colunas.codigo), String::new()),
+            (None, _) => separar_codigo(componente),
+        };
+
+        let horario = codigo_horario(horario);
+        if horario.is_empty() {
+            importado
+                .rejeitadas
+                .push(RejectedRow { celulas, motivo: RowRejection::MissingTime });
+            continue;
+        }
+
+        let nome_disciplina = match nome.is_empty() {
+            true => &codigo,
+            false => &nome,
+        };
+
+        match SigaaTime::parse_codes(&horario) {
+            Ok(sigaa_times) => {
+                let (turma, local) = (celula(colunas.turma), celula(colunas.local));
+                let disciplina = Disciplina { codigo: Some(codigo.clone()).filter(|codigo| !codigo.is_empty()), turma: Some(turma.clone()).filter(|turma| !turma.is_empty()), local: Some(local.clone()).filter(|local| !local.is_empty()), ..Disciplina::new(nome_disciplina, sigaa_times) };
+                importado
+                    .matriculas
+                    .push(Matricula { codigo, nome, turma, local, horario, disciplina });
+            }
+            Err(erro) => importado
+                .rejeitadas
+                .push(RejectedRow { celulas, motivo: RowRejection::InvalidTime(erro) }),
+        }
+    }
+
+    match colunas {
+        Some(_) => Ok(importado),
+        None => Err(SigaaImportError::MissingHeader),
+    }
+}
+
+/// Reconhece uma linha de cabeçalho, que precisa ter ao menos a coluna de horário e a de componente ou código.
+fn cabecalho(celulas: &[String]) -> Option<Colunas> {
+    let mut colunas = Colunas::default();
+    let mut horario = None;
+
+    for (indice, celula) in celulas.iter().enumerate() {
+        match normalizar(celula).as_str() {
+            "codigo" | "cod" => colunas.codigo = Some(indice),
+            "componente curricular" | "componente" | "disciplina" | "nome" => colunas.componente = Some(indice),
+            "turma" => colunas.turma = Some(indice),
+            "local" | "sala" => colunas.local = Some(indice),
+            "horario" | "horarios" => horario = Some(indice),
+            _ => {}
+        }
+    }
+
+    colunas.horario = horario?;
+    match colunas.codigo.is_some() || colunas.componente.is_some() {
+        true => Some(colunas),
+        false => None,
+    }
+}
+
+/// Separa `DIM0119 - ESTRUTURAS DE DADOS` em código e nome. Sem código reconhecível, tudo é nome.
+fn separar_codigo(componente: &str) -> (String, String) {
+    match componente.split_once(" - ") {
+        Some((codigo, nome)) if !codigo.is_empty() && codigo.chars().all(|c| c.is_ascii_alphanumeric()) && codigo.chars().any(|c| c.is_ascii_digit()) => (codigo.to_string(), nome.trim().to_string()),
+        _ => (String::new(), componente.to_string()),
+    }
+}
+
+/// Códigos de horário no começo da célula, ignorando o que vem depois (ex: o período das aulas).
+fn codigo_horario(celula: &str) -> String {
+    celula
+        .split_whitespace()
+        .take_while(|token| token.starts_with(|c: char| c.is_ascii_digit()) && token.chars().all(|c| c.is_ascii_alphanumeric()))
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Texto em minúsculas, sem acentos, sem pontuação nas pontas e com espaços simples.
+fn normalizar(texto: &str) -> String {
+    texto
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'Á' | 'À' | 'Â' | 'Ã' => 'a',
+            'é' | 'ê' | 'É' | 'Ê' => 'e',
+            'í' | 'Í' => 'i',
+            'ó' | 'ô' | 'õ' | 'Ó' | 'Ô' | 'Õ' => 'o',
+            'ú' | 'Ú' => 'u',
+            'ç' | 'Ç' => 'c',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect::<String>()
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn celulas_texto(linha: &str) -> Vec<String> {
+    let celulas: Vec<&str> = match linha.contains('\t') {
+        true => linha.split('\t').collect(),
+        false => linha
+            .split("  ")
+            .filter(|celula| !celula.trim().is_empty())
+            .collect(),
+    };
+
+    celulas
+        .into_iter()
+        .map(|celula| celula.trim().to_string())
+        .collect()
+}
+
+/// Células de cada linha (`<tr>`) das tabelas do HTML, já como texto.
+fn linhas_html(html: &str) -> Vec<Vec<String>> {
+    // `to_ascii_lowercase` não muda o tamanho em bytes, então as posições valem para os dois textos.
+    let minusculo = html.to_ascii_lowercase();
+    let mut linhas = Vec::new();
+    let mut posicao = 0;
+
+    while let Some(inicio) = minusculo[posicao..]
+        .find("<tr")
+        .map(|inicio| posicao + inicio)
+    {
+        let fim = [minusculo[inicio + 3..].find("</tr"), minusculo[inicio + 3..].find("<tr")]
+            .into_iter()
+            .flatten()
+            .min()
+            .map_or(html.len(), |fim| inicio + 3 + fim);
+
+        linhas.push(celulas_html(&html[inicio..fim], &minusculo[inicio..fim]));
+        posicao = fim;
+    }
+
+    linhas
+}
+
+fn celulas_html(linha: &str, minusculo: &str) -> Vec<String> {
+    let mut celulas = Vec::new();
+    let mut posicao = 0;
+
+    loop {
+        let abertura = [minusculo[posicao..].find("<td"), minusculo[posicao..].find("<th")]
+            .into_iter()
+            .flatten()
+            .min();
+        let Some(abertura) = abertura.map(|abertura| posicao + abertura) else { break };
+        let Some(conteudo) = minusculo[abertura..]
+            .find('>')
+            .map(|fim| abertura + fim + 1)
+        else {
+            break;
+        };
+
+        let fim = [minusculo[conteudo..].find("</td"), minusculo[conteudo..].find("</th"), minusculo[conteudo..].find("<td"), minusculo[conteudo..].find("<th")]
+            .into_iter()
+            .flatten()
+            .min()
+            .map_or(linha.len(), |fim| conteudo + fim);
+
+        celulas.push(texto_html(&linha[conteudo..fim]));
+        posicao = fim;
+    }
+
+    celulas
+}
+
+/// Remove as tags, decodifica as entidades e junta os espaços de um trecho de HTML.
+fn texto_html(html: &str) -> String {
+    let mut texto = String::new();
+    let mut dentro_tag = false;
+
+    for c in html.chars() {
+        match (c, dentro_tag) {
+            ('<', _) => dentro_tag = true,
+            ('>', true) => {
+                dentro_tag = false;
+                texto.push(' ');
+            }
+            (c, false) => texto.push(c),
+            _ => {}
+        }
+    }
+
+    decodificar_entidades(&texto)
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn decodificar_entidades(texto: &str) -> String {
+    let mut decodificado = String::new();
+    let mut resto = texto;
+
+    while let Some(inicio) = resto.find('&') {
+        decodificado.push_str(&resto[..inicio]);
+        resto = &resto[inicio..];
+
+        let entidade = resto[1..]
+            .find(';')
+            .filter(|fim| *fim <= 10)
+            .and_then(|fim| entidade(&resto[1..fim + 1]).map(|c| (c, fim + 2)));
+
+        match entidade {
+            Some((c, tamanho)) => {
+                decodificado.push(c);
+                resto = &resto[tamanho..];
+            }
+            None => {
+                decodificado.push('&');
+                resto = &resto[1..];
+            }
+        }
+    }
+
+    decodificado.push_str(resto);
+    decodificado
+}
+
+/// Caractere de uma entidade HTML (sem `&` e `;`): as numéricas e as nomeadas comuns em português.
+fn entidade(nome: &str) -> Option<char> {
+    if let Some(numero) = nome.strip_prefix('#') {
+        let codigo = match numero.strip_prefix(['x', 'X']) {
+            Some(hexadecimal) => u32::from_str_radix(hexadecimal, 16).ok()?,
+            None => numero.parse().ok()?,
+        };
+        return char::from_u32(codigo);
+    }
+
+    let c = match nome {
+        "nbsp" => ' ',
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "aacute" => 'á',
+        "Aacute" => 'Á',
+        "agrave" => 'à',
+        "Agrave" => 'À',
+        "acirc" => 'â',
+        "Acirc" => 'Â',
+        "atilde" => 'ã',
+        "Atilde" => 'Ã',
+        "eacute" => 'é',
+        "Eacute" => 'É',
+        "ecirc" => 'ê',
+        "Ecirc" => 'Ê',
+        "iacute" => 'í',
+        "Iacute" => 'Í',
+        "oacute" => 'ó',
+        "Oacute" => 'Ó',
+        "ocirc" => 'ô',
+        "Ocirc" => 'Ô',
+        "otilde" => 'õ',
+        "Otilde" => 'Õ',
+        "uacute" => 'ú',
+        "Uacute" => 'Ú',
+        "ccedil" => 'ç',
+        "Ccedil" => 'Ç',
+        "ndash" => '–',
+        "mdash" => '—',
+        "ordm" => 'º',
+        "ordf" => 'ª',
+        _ => return None,
+    };
+
+    Some(c)
+}
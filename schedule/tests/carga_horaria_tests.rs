@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod carga_horaria_tests {
+    use chrono::NaiveDate;
+    use class::Disciplina;
+    use schedule::{DiasLetivos, OverlapPolicy, Schedule, Semestre};
+    use stf::{Dia, Hora, Periodo, SigaaSlot, TabelaHorarios};
+
+    fn data(ano: i32, mes: u32, dia: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(ano, mes, dia).unwrap()
+    }
+
+    #[test]
+    fn should_count_class_days_of_a_semester() {
+        // 2025-03-10 (segunda) a 2025-07-12 (sábado): 18 datas de cada dia, de segunda a sábado.
+        let mut semestre = Semestre::new(data(2025, 3, 10), data(2025, 7, 12)).unwrap();
+        semestre.add_feriado(data(2025, 4, 21));
+        semestre.add_feriado(data(2025, 5, 1));
+
+        let dias = DiasLetivos::from(&semestre);
+
+        assert_eq!(dias.aulas(Dia::Segunda), 17);
+        assert_eq!(dias.aulas(Dia::Terça), 18);
+        assert_eq!(dias.aulas(Dia::Quinta), 17);
+        assert_eq!(dias.aulas(Dia::Sabado), 18);
+    }
+
+    #[test]
+    fn should_use_real_slot_durations() {
+        let mut tabela = TabelaHorarios::ufrn();
+        tabela
+            .definir(Periodo::N3, Hora::new(20, 35).unwrap(), Hora::new(21, 20).unwrap())
+            .unwrap();
+        let dias = DiasLetivos::semanas(10).sem_feriados(&[Dia::Terça]);
+
+        let slots = SigaaSlot::parse_codes("2T4 3N34").unwrap();
+
+        // T4: 50 min x 10 segundas; N3 + N4: (45 + 50) min x 9 terças.
+        assert_eq!(dias.minutos(&tabela, &slots), 50 * 10 + 95 * 9);
+    }
+
+    #[test]
+    fn should_count_overlapped_slots_once_for_the_schedule() {
+        let mut schedule = Schedule::with_policy(OverlapPolicy::Allow);
+        schedule
+            .insert(Disciplina::new_stringify("Física", "24M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Física - Laboratório", "4M12 6T12").unwrap())
+            .unwrap();
+
+        let carga = schedule.workload(&TabelaHorarios::default(), &DiasLetivos::default());
+
+        assert_eq!(schedule.weekly_minutes(&TabelaHorarios::default()), 300);
+        assert_eq!(carga.minutos_semanais, 300);
+        assert_eq!(carga.horas_periodo(), 90.0);
+        assert_eq!(carga.disciplinas.len(), 2);
+        assert_eq!(carga.disciplinas[0].minutos_semanais, 200);
+        assert_eq!(carga.disciplinas[1].horas_periodo(), 60.0);
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod diff_tests {
+    use class::Disciplina;
+    use schedule::{MergeConflict, OverlapPolicy, Schedule};
+
+    fn cronograma(disciplinas: &[Disciplina]) -> Schedule {
+        let mut schedule = Schedule::new();
+        for disciplina in disciplinas {
+            schedule.insert(disciplina.clone()).unwrap();
+        }
+        schedule
+    }
+
+    fn turma(nome: &str, horario: &str, turma: &str) -> Disciplina {
+        let mut disciplina = Disciplina::new_stringify(nome, horario).unwrap();
+        disciplina.turma = Some(turma.to_string());
+        disciplina
+    }
+
+    #[test]
+    fn diff_should_report_added_removed_moved_and_changed() {
+        let calculo = turma("Cálculo I", "24M12", "01");
+        let fisica = turma("Física I", "35M12", "01");
+        let quimica = turma("Química", "6T12", "01");
+
+        let antes = cronograma(&[calculo.clone(), fisica.clone(), quimica.clone()]);
+        let depois = cronograma(&[turma("Cálculo I", "24T34", "02"), turma("Física I", "35M12", "02"), quimica.clone(), turma("Estágio", "6N12", "01")]);
+
+        let diff = antes.diff(&depois);
+
+        assert_eq!(diff.adicionadas, [turma("Estágio", "6N12", "01")]);
+        assert!(diff.removidas.is_empty());
+        assert_eq!(diff.movidas.len(), 1);
+        assert_eq!(diff.movidas[0].antes, calculo);
+        assert_eq!(diff.alteradas.len(), 1);
+        assert_eq!(diff.alteradas[0].depois.turma.as_deref(), Some("02"));
+
+        let horarios: Vec<String> = diff
+            .horarios
+            .iter()
+            .map(|mudanca| mudanca.horario.to_string())
+            .collect();
+        assert_eq!(horarios, ["2M12", "3M12", "4M12", "5M12", "2T34", "4T34", "6N12"]);
+
+        assert_eq!(
+            diff.to_string(),
+            "+ Estágio (6N12)\n\
+             ~ Cálculo I: 2M12 4M12 -> 2T34 4T34\n\
+             * Física I (3M12 5M12): dados alterados\n  \
+             2M12: Cálculo I -> (vazio)\n  \
+             3M12: Física I -> Física I\n  \
+             4M12: Cálculo I -> (vazio)\n  \
+             5M12: Física I -> Física I\n  \
+             2T34: (vazio) -> Cálculo I\n  \
+             4T34: (vazio) -> Cálculo I\n  \
+             6N12: (vazio) -> Estágio\n"
+        );
+    }
+
+    #[test]
+    fn diff_of_equal_schedules_should_be_empty() {
+        let schedule = cronograma(&[turma("Cálculo I", "24M12", "01")]);
+
+        let diff = schedule.diff(&schedule.clone());
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "Sem alterações\n");
+    }
+
+    #[test]
+    fn merge_should_combine_independent_changes() {
+        let calculo = turma("Cálculo I", "24M12", "01");
+        let fisica = turma("Física I", "35M12", "01");
+
+        let base = cronograma(&[calculo.clone(), fisica.clone()]);
+        let nossa = cronograma(&[calculo.clone(), turma("Física I", "35T12", "02")]);
+        let deles = cronograma(&[fisica.clone(), turma("Química", "6M12", "01")]);
+
+        let merge = Schedule::merge(&base, &nossa, &deles);
+
+        assert!(merge.conflitos.is_empty());
+        assert!(merge
+            .schedule
+            .diff(&cronograma(&[turma("Física I", "35T12", "02"), turma("Química", "6M12", "01")]))
+            .is_empty());
+    }
+
+    #[test]
+    fn merge_should_keep_ours_when_both_changed() {
+        let base = cronograma(&[turma("Cálculo I", "24M12", "01")]);
+        let nossa = cronograma(&[turma("Cálculo I", "24T12", "02")]);
+        let deles = cronograma(&[turma("Cálculo I", "35M12", "03")]);
+
+        let merge = Schedule::merge(&base, &nossa, &deles);
+
+        assert_eq!(merge.conflitos, [MergeConflict::BothChanged { nome: "Cálculo I".to_string(), nossa: vec![turma("Cálculo I", "24T12", "02")], deles: vec![turma("Cálculo I", "35M12", "03")] }]);
+        assert!(merge.schedule.diff(&nossa).is_empty());
+    }
+
+    #[test]
+    fn merge_should_flag_disciplines_that_lost_their_slot() {
+        let base = cronograma(&[turma("Cálculo I", "24M12", "01")]);
+        let nossa = cronograma(&[turma("Cálculo I", "24M12", "01"), turma("Física I", "3M12", "01")]);
+        let deles = cronograma(&[turma("Cálculo I", "35M12", "02")]);
+
+        let merge = Schedule::merge(&base, &nossa, &deles);
+
+        match merge.conflitos.as_slice() {
+            [MergeConflict::SlotTaken { disciplina, conflitos }] => {
+                assert_eq!(disciplina, &turma("Cálculo I", "35M12", "02"));
+                assert_eq!(conflitos.len(), 1);
+                assert_eq!(conflitos[0].disciplina.nome, "Física I");
+            }
+            outros => panic!("conflitos inesperados: {outros:?}"),
+        }
+        assert!(merge
+            .schedule
+            .diff(&cronograma(&[turma("Física I", "3M12", "01")]))
+            .is_empty());
+    }
+
+    #[test]
+    fn merge_should_flag_lost_slots_whatever_the_policy() {
+        for politica in [OverlapPolicy::Warn, OverlapPolicy::Allow] {
+            let mut base = cronograma(&[turma("Cálculo I", "24M12", "01")]);
+            base.set_policy(politica);
+            let mut nossa = cronograma(&[turma("Cálculo I", "24M12", "01"), turma("Física I", "3M12", "01")]);
+            nossa.set_policy(politica);
+            let mut deles = cronograma(&[turma("Cálculo I", "35M12", "02")]);
+            deles.set_policy(politica);
+
+            let merge = Schedule::merge(&base, &nossa, &deles);
+
+            match merge.conflitos.as_slice() {
+                [MergeConflict::SlotTaken { disciplina, conflitos }] => {
+                    assert_eq!(disciplina, &turma("Cálculo I", "35M12", "02"));
+                    assert_eq!(conflitos.len(), 1);
+                    assert_eq!(conflitos[0].disciplina.nome, "Física I");
+                }
+                outros => panic!("conflitos inesperados com {politica:?}: {outros:?}"),
+            }
+            assert!(merge.schedule.overlaps().is_empty());
+            assert_eq!(merge.schedule.policy(), politica);
+        }
+    }
+}
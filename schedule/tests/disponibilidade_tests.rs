@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod disponibilidade_tests {
+    use class::Disciplina;
+    use schedule::Schedule;
+    use stf::{Dia, SigaaTime, TabelaHorarios};
+
+    fn cronograma(horario: &str) -> Schedule {
+        let mut schedule = Schedule::new();
+        schedule
+            .insert(Disciplina::new_stringify("Ocupado", horario).unwrap())
+            .unwrap();
+        schedule
+    }
+
+    fn codigos(blocos: &[schedule::BlocoLivre]) -> Vec<String> {
+        blocos.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn free_times_should_skip_occupied_slots() {
+        let schedule = cronograma("234567M12 234567M34 234567M56 234567T12 234567T34 234567T56 234567N12 23456N34");
+
+        assert_eq!(
+            schedule
+                .free_times()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [SigaaTime::try_from("7N34").unwrap()]
+        );
+        assert_eq!(Schedule::new().free_times().len(), 48);
+    }
+
+    #[test]
+    fn free_blocks_should_not_cross_turnos_or_days() {
+        let schedule = cronograma("234567M34 34567M12 34567M56 34567T12 34567T34 34567T56 34567N12 34567N34");
+
+        let blocos = schedule.free_blocks();
+
+        assert_eq!(codigos(&blocos), ["2M12", "2M56", "2T123456", "2N1234"]);
+        assert_eq!(blocos[2].len(), 3);
+        assert_eq!(
+            blocos[2]
+                .bloco(&TabelaHorarios::default())
+                .unwrap()
+                .to_string(),
+            "2 13:00-18:30"
+        );
+        assert!(blocos
+            .iter()
+            .all(|bloco| bloco.dia == Dia::Segunda));
+    }
+
+    #[test]
+    fn common_free_blocks_should_intersect_and_rank() {
+        let ana = cronograma("234567M12 234567M34 234567M56 234567T12 234567N12 234567N34 3456T34 3456T56");
+        let bruno = cronograma("234567M12 234567M34 234567M56 234567T56 234567N12 234567N34 34567T12");
+        let carla = cronograma("234567M12 234567M34 234567M56 234567N12 234567N34 2T12");
+
+        assert_eq!(codigos(&Schedule::common_free_blocks(&[ana.clone(), bruno.clone(), carla.clone()], 1)), ["2T34", "7T34"]);
+        assert_eq!(codigos(&Schedule::common_free_blocks(&[bruno.clone(), carla.clone()], 1)), ["2T34", "3T34", "4T34", "5T34", "6T34", "7T34"]);
+        assert_eq!(codigos(&Schedule::common_free_blocks(std::slice::from_ref(&carla), 2)), ["3T123456", "4T123456", "5T123456", "6T123456", "7T123456", "2T3456"]);
+        assert!(Schedule::common_free_blocks(&[ana, bruno, carla], 2).is_empty());
+        assert_eq!(Schedule::common_free_blocks(&[], 8).len(), 0);
+        assert_eq!(Schedule::common_free_blocks(&[], 3).len(), 12);
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod documento_tests {
+    use class::Disciplina;
+    use schedule::{Documento, DocumentoError, FormatoGrade, OverlapPolicy, Schedule, VERSAO_DOCUMENTO};
+    use stf::SigaaTime;
+
+    const V0_DISCIPLINAS: &str = include_str!("fixtures/documento/v0_disciplinas.json");
+    /// `Schedule` da primeira versão serializado diretamente: a matriz inteira, sem versão.
+    const V0_SCHEDULE: &str = include_str!("fixtures/documento/v0_schedule.json");
+    const V1: &str = include_str!("fixtures/documento/v1.json");
+
+    fn disciplinas() -> Vec<Disciplina> {
+        vec![Disciplina::new_stringify("Cálculo I", "24M12").unwrap(), Disciplina::new_stringify("Física I", "35T34").unwrap()]
+    }
+
+    #[test]
+    fn should_migrate_the_unversioned_discipline_list() {
+        let documento = Documento::from_json(V0_DISCIPLINAS).unwrap();
+
+        assert_eq!(documento.versao, VERSAO_DOCUMENTO);
+        assert_eq!(documento.disciplinas, disciplinas());
+        assert_eq!(documento.semestre, None);
+        assert_eq!(documento.configuracoes.politica, OverlapPolicy::Reject);
+    }
+
+    #[test]
+    fn should_migrate_the_serialized_schedule_grid() {
+        let documento = Documento::from_json(V0_SCHEDULE).unwrap();
+
+        assert_eq!(documento.versao, VERSAO_DOCUMENTO);
+        assert_eq!(documento.disciplinas, disciplinas());
+    }
+
+    #[test]
+    fn should_load_every_field_of_version_1() {
+        let documento = Documento::from_json(V1).unwrap();
+
+        assert_eq!(documento.metadados.titulo, "2025.1");
+        assert_eq!(
+            documento
+                .semestre
+                .as_ref()
+                .unwrap()
+                .feriados
+                .len(),
+            2
+        );
+        assert_eq!(documento.disciplinas, disciplinas());
+        assert_eq!(documento.configuracoes.politica, OverlapPolicy::Warn);
+        assert_eq!(documento.to_schedule().unwrap().policy(), OverlapPolicy::Warn);
+    }
+
+    #[test]
+    fn should_round_trip_the_current_version() {
+        let documento = Documento::from_json(V1).unwrap();
+
+        assert_eq!(Documento::from_json(&documento.to_json()).unwrap(), documento);
+        assert_eq!(Documento::from_value(documento.to_value()).unwrap(), documento);
+    }
+
+    #[test]
+    fn should_keep_the_class_data_of_each_discipline() {
+        let mut documento = Documento::from_json(V1).unwrap();
+        documento.disciplinas[0].codigo = Some("MAT0311".to_string());
+        documento.disciplinas[0].professores = vec!["Ana".to_string()];
+        documento.disciplinas[0].creditos = Some(4);
+
+        let json = documento.to_json();
+
+        assert!(!json.contains("carga_horaria"));
+        assert_eq!(Documento::from_json(&json).unwrap(), documento);
+    }
+
+    #[test]
+    fn should_keep_pinned_abbreviations() {
+        let mut schedule = Schedule::new();
+        schedule
+            .pin_abbreviation("Cálculo I", "CALC")
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap())
+            .unwrap();
+
+        let documento = Documento::from_json(&Documento::new(&schedule).to_json()).unwrap();
+        let recarregado = documento.to_schedule().unwrap();
+
+        assert_eq!(recarregado.pinned_abbreviations(), schedule.pinned_abbreviations());
+        assert_eq!(documento.disciplinas[0].abreviacao, "CALC");
+    }
+
+    #[test]
+    fn should_rebuild_the_same_schedule_from_every_version() {
+        let mut esperado = Schedule::new();
+        for disciplina in disciplinas() {
+            esperado.insert(disciplina).unwrap();
+        }
+
+        for fixture in [V0_DISCIPLINAS, V0_SCHEDULE] {
+            assert!(
+                Documento::from_json(fixture)
+                    .unwrap()
+                    .to_schedule()
+                    .unwrap()
+                    == esperado
+            );
+        }
+    }
+
+    #[test]
+    fn should_write_times_as_sigaa_codes() {
+        let mut schedule = Schedule::new();
+        schedule
+            .insert(Disciplina::new_stringify("Física I", "3T34").unwrap())
+            .unwrap();
+
+        let valor = Documento::new(&schedule).to_value();
+
+        assert_eq!(valor["disciplinas"][0]["sigaa_time"], serde_json::json!(["3T34"]));
+        assert!(
+            Documento::from_value(valor)
+                .unwrap()
+                .to_schedule()
+                .unwrap()
+                == schedule
+        );
+    }
+
+    #[test]
+    fn should_read_times_in_both_formats() {
+        let json = r#"{"versao": 1, "disciplinas": [
+            {"nome": "A", "abreviacao": "A", "sigaa_time": ["2M12", {"dia": "Terça", "turno": {"Tarde": "Segundo"}}]}
+        ]}"#;
+
+        let documento = Documento::from_json(json).unwrap();
+        assert_eq!(documento.disciplinas[0], Disciplina::new_stringify("A", "2M12 3T34").unwrap());
+
+        let invalido = json.replace("2M12", "2M78");
+        assert!(matches!(Documento::from_json(&invalido), Err(DocumentoError::InvalidJson(erro)) if erro.contains("2M78")));
+    }
+
+    #[test]
+    fn should_migrate_a_directly_serialized_schedule() {
+        let mut schedule = Schedule::with_format(FormatoGrade::semana_completa());
+        schedule.set_policy(OverlapPolicy::Warn);
+        schedule
+            .pin_abbreviation("Cálculo I", "CALC")
+            .unwrap();
+        for disciplina in disciplinas() {
+            schedule.insert(disciplina).unwrap();
+        }
+
+        let documento = Documento::from_json(&serde_json::to_string(&schedule).unwrap()).unwrap();
+
+        assert_eq!(documento.versao, VERSAO_DOCUMENTO);
+        assert_eq!(documento.disciplinas, disciplinas());
+        assert_eq!(documento.configuracoes.politica, OverlapPolicy::Warn);
+        assert_eq!(documento.configuracoes.formato, FormatoGrade::semana_completa());
+        assert_eq!(documento.configuracoes.abreviacoes["Cálculo I"], "CALC");
+        assert!(documento.to_schedule().unwrap() == schedule);
+
+        assert!(Documento::from_json(r#"{"disciplinas": []}"#)
+            .unwrap()
+            .disciplinas
+            .is_empty());
+    }
+
+    #[test]
+    fn should_report_an_old_grid_that_does_not_match_its_disciplines() {
+        let mut valor: serde_json::Value = serde_json::from_str(V0_SCHEDULE).unwrap();
+        valor[0][2]["disciplina"] = serde_json::Value::Null;
+
+        assert_eq!(Documento::from_value(valor), Err(DocumentoError::InconsistentGrid(SigaaTime::try_from("4M12").unwrap())));
+    }
+
+    #[test]
+    fn should_reject_unknown_or_newer_documents() {
+        assert_eq!(Documento::from_json(r#"{"versao": 99, "disciplinas": []}"#), Err(DocumentoError::UnsupportedVersion(99)));
+        assert_eq!(Documento::from_json(r#"{"cronograma": []}"#), Err(DocumentoError::UnknownFormat));
+        assert_eq!(Documento::from_json("42"), Err(DocumentoError::UnknownFormat));
+        assert!(matches!(Documento::from_json("[{"), Err(DocumentoError::InvalidJson(_))));
+    }
+
+    #[test]
+    fn should_report_documents_with_conflicting_disciplines() {
+        let json = r#"{"versao": 1, "disciplinas": [
+            {"nome": "A", "abreviacao": "A", "sigaa_time": [{"dia": "Segunda", "turno": {"Manhã": "Primeiro"}}]},
+            {"nome": "B", "abreviacao": "B", "sigaa_time": [{"dia": "Segunda", "turno": {"Manhã": "Primeiro"}}]}
+        ]}"#;
+
+        assert!(matches!(Documento::from_json(json).unwrap().to_schedule(), Err(DocumentoError::Schedule(_))));
+    }
+}
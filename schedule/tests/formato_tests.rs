@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod formato_tests {
+    use chrono::NaiveDate;
+    use class::Disciplina;
+    use schedule::{Criterio, DiasLetivos, Documento, FormatoGrade, Pesos, Schedule, ScheduleError, Semestre};
+    use stf::{Dia, HorarioDiurno, HorarioNoturno, SigaaTime, Turno};
+
+    fn sem_noite_com_domingo() -> FormatoGrade {
+        let turnos: Vec<Turno> = Turno::TODOS
+            .into_iter()
+            .filter(|turno| !matches!(turno, Turno::Noite(_)))
+            .collect();
+        FormatoGrade::new(&Dia::TODOS, &turnos)
+    }
+
+    #[test]
+    fn default_format_should_be_monday_to_saturday() {
+        let schedule = Schedule::new();
+
+        assert_eq!(schedule.format().dias(), &Dia::TODOS[1..]);
+        assert_eq!(schedule.format().turnos(), &Turno::TODOS);
+        assert_eq!(schedule.free_times().len(), 48);
+        assert!(schedule.get_from_str("1M12").is_none());
+    }
+
+    #[test]
+    fn grid_should_follow_the_format() {
+        let mut schedule = Schedule::with_format(sem_noite_com_domingo());
+
+        assert_eq!(schedule.free_times().len(), 42);
+        assert!(schedule.get_from_str("1M12").is_some());
+        assert!(schedule.get_from_str("2N12").is_none());
+
+        let missa = Disciplina::new_stringify("Coral", "1M34").unwrap();
+        schedule.insert(missa.clone()).unwrap();
+        assert_eq!(schedule.get_from_str("1M34").unwrap().disciplina, Some(missa));
+
+        let noturna = Disciplina::new_stringify("Cálculo I", "24N12").unwrap();
+        assert_eq!(schedule.insert(noturna.clone()), Err(ScheduleError::TimeNotFound(SigaaTime::new(Dia::Segunda, Turno::Noite(HorarioNoturno::Primeiro)))));
+        assert!(schedule.conflicts(&noturna).is_empty());
+        assert_eq!(schedule.remove(noturna), Err(ScheduleError::DisciplineNotFoundToRemove));
+    }
+
+    #[test]
+    fn sunday_should_have_its_own_criterion_and_class_days() {
+        let mut schedule = Schedule::with_format(FormatoGrade::semana_completa());
+        schedule
+            .insert(Disciplina::new_stringify("Coral", "1M34").unwrap())
+            .unwrap();
+
+        let pontuacao = schedule.score(&Pesos::default());
+        let ocorrencias = |criterio: Criterio| {
+            pontuacao
+                .criterios
+                .iter()
+                .find(|item| item.criterio == criterio)
+                .unwrap()
+                .ocorrencias
+        };
+
+        assert_eq!(ocorrencias(Criterio::Domingo), 1);
+        assert_eq!(ocorrencias(Criterio::Sabado), 0);
+        assert_eq!(ocorrencias(Criterio::DiaLivre), 6);
+
+        // 2025-03-09 é um domingo.
+        let semestre = Semestre::new(NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(), NaiveDate::from_ymd_opt(2025, 3, 16).unwrap()).unwrap();
+        assert_eq!(DiasLetivos::from(&semestre).aulas(Dia::Domingo), 2);
+        assert_eq!(DiasLetivos::semanas(10).aulas(Dia::Domingo), 10);
+    }
+
+    #[test]
+    fn format_should_be_saved_in_the_document() {
+        let formato = FormatoGrade::new(&[Dia::Sabado, Dia::Domingo], &[Turno::Manhã(HorarioDiurno::Primeiro)]);
+        let mut schedule = Schedule::with_format(formato.clone());
+        schedule
+            .insert(Disciplina::new_stringify("Plantão", "17M12").unwrap())
+            .unwrap();
+
+        let json = Documento::new(&schedule).to_json();
+        let recarregado = Documento::from_json(&json)
+            .unwrap()
+            .to_schedule()
+            .unwrap();
+
+        assert_eq!(recarregado.format(), &formato);
+        assert!(recarregado.diff(&schedule).is_empty());
+        assert!(!Documento::new(&Schedule::new())
+            .to_json()
+            .contains("formato"));
+    }
+
+    #[test]
+    fn set_format_should_keep_disciplines_that_fit() {
+        let mut schedule = Schedule::new();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap())
+            .unwrap();
+
+        schedule
+            .set_format(sem_noite_com_domingo())
+            .unwrap();
+        assert_eq!(
+            schedule
+                .get_from_str("4M12")
+                .unwrap()
+                .disciplina
+                .as_ref()
+                .map(|d| d.nome.as_str()),
+            Some("Cálculo I")
+        );
+
+        let so_segunda = FormatoGrade::new(&[Dia::Segunda], &Turno::TODOS);
+        assert!(schedule.set_format(so_segunda).is_err());
+        assert_eq!(schedule.format(), &sem_noite_com_domingo());
+    }
+}
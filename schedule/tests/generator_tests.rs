@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod generator_tests {
+    use class::Disciplina;
+    use schedule::{Oferta, Schedule};
+
+    /// Índice da turma de cada oferta presente no cronograma.
+    fn turmas_escolhidas(cronograma: &Schedule, ofertas: &[Oferta]) -> Vec<usize> {
+        ofertas
+            .iter()
+            .map(|oferta| {
+                oferta
+                    .turmas
+                    .iter()
+                    .position(|turma| {
+                        turma.sigaa_time.iter().all(|sigaa_time| {
+                            cronograma
+                                .get(sigaa_time)
+                                .unwrap()
+                                .disciplina
+                                .as_ref()
+                                == Some(turma)
+                        })
+                    })
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_enumerate_every_conflict_free_combination() {
+        let ofertas = [Oferta::new("Cálculo I", &["24M12", "35M12"]).unwrap(), Oferta::new("Física I", &["24M12", "35M34"]).unwrap(), Oferta::new("Química", &["35M12", "6M1234"]).unwrap()];
+
+        let cronogramas = Schedule::generate(&ofertas);
+
+        // Cálculo 24M12 + Física 35M34 + (Química 35M12 | 6M1234)
+        // Cálculo 35M12 + Física (24M12 | 35M34) + Química 6M1234
+        assert_eq!(cronogramas.len(), 4);
+        assert!(cronogramas
+            .iter()
+            .all(|cronograma| cronograma.overlaps().is_empty()));
+
+        let escolhas: Vec<Vec<usize>> = cronogramas
+            .iter()
+            .map(|cronograma| turmas_escolhidas(cronograma, &ofertas))
+            .collect();
+        assert_eq!(escolhas, vec![vec![0, 1, 0], vec![0, 1, 1], vec![1, 0, 1], vec![1, 1, 1]]);
+    }
+
+    #[test]
+    fn should_return_nothing_when_a_subject_cannot_fit() {
+        let ofertas = [Oferta::new("Cálculo I", &["24M12"]).unwrap(), Oferta::new("Física I", &["24M12", "2M1234"]).unwrap()];
+
+        assert!(Schedule::generate(&ofertas).is_empty());
+        assert!(Schedule::generate(&[Oferta { nome: "Vazia".to_string(), turmas: Vec::new() }]).is_empty());
+        assert_eq!(Schedule::generate(&[]).len(), 1);
+    }
+
+    #[test]
+    fn should_ignore_repeated_classes_and_respect_the_limit() {
+        let ofertas = [Oferta::new("Cálculo I", &["24M12", "24M12", "35M12"]).unwrap(), Oferta::new("Física I", &["2T12", "3T12", "4T12"]).unwrap()];
+
+        assert_eq!(Schedule::generate(&ofertas).len(), 6);
+        assert_eq!(Schedule::generate_up_to(&ofertas, 2).len(), 2);
+    }
+
+    #[test]
+    fn should_handle_dozens_of_subjects() {
+        // 40 componentes com 6 turmas cada, todas disputando os mesmos 12 horários da manhã e da tarde:
+        // sem poda, a busca visitaria 6^40 combinações antes de concluir que não há solução.
+        let horarios = ["2M12", "3M12", "4M12", "5M12", "6M12", "7M12", "2T12", "3T12", "4T12", "5T12", "6T12", "7T12"];
+        let ofertas: Vec<Oferta> = (0..40)
+            .map(|indice| Oferta {
+                nome: format!("Componente {indice}"),
+                turmas: (0..6)
+                    .map(|turma| Disciplina::new_stringify(&format!("Componente {indice}"), horarios[(indice + turma) % horarios.len()]).unwrap())
+                    .collect(),
+            })
+            .collect();
+
+        assert!(Schedule::generate(&ofertas).is_empty());
+        assert_eq!(Schedule::generate_up_to(&ofertas[..12], 5).len(), 5);
+    }
+}
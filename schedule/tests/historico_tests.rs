@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod historico_tests {
+    use class::Disciplina;
+    use schedule::{Documento, Edicao, Historico, OverlapPolicy, Schedule, ScheduleError};
+    use stf::SigaaTime;
+
+    fn disciplina(nome: &str, horario: &str) -> Disciplina {
+        Disciplina::new_stringify(nome, horario).unwrap()
+    }
+
+    fn ocupante(schedule: &Schedule, horario: &str) -> Option<String> {
+        schedule
+            .get_from_str(horario)
+            .unwrap()
+            .disciplina
+            .as_ref()
+            .map(|disciplina| disciplina.nome.clone())
+    }
+
+    #[test]
+    fn should_undo_and_redo_every_kind_of_edit() {
+        let mut schedule = Schedule::new();
+        let mut historico = Historico::new();
+        let calculo = disciplina("Cálculo I", "24M12");
+
+        historico
+            .execute(&mut schedule, Edicao::Insert(calculo.clone()))
+            .unwrap();
+        historico
+            .execute(&mut schedule, Edicao::Rename { disciplina: calculo.clone(), nome: "Cálculo II".to_string() })
+            .unwrap();
+        historico
+            .execute(
+                &mut schedule,
+                Edicao::Move {
+                    disciplina: disciplina("Cálculo II", "24M12"),
+                    horarios: ["3T12", "5T12"]
+                        .map(|h| SigaaTime::try_from(h).unwrap())
+                        .into(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(ocupante(&schedule, "3T12").as_deref(), Some("Cálculo II"));
+        assert_eq!(ocupante(&schedule, "2M12"), None);
+
+        historico.undo(&mut schedule).unwrap();
+        assert_eq!(ocupante(&schedule, "2M12").as_deref(), Some("Cálculo II"));
+        assert_eq!(ocupante(&schedule, "3T12"), None);
+
+        historico.undo(&mut schedule).unwrap();
+        assert_eq!(ocupante(&schedule, "2M12").as_deref(), Some("Cálculo I"));
+
+        historico.undo(&mut schedule).unwrap();
+        assert_eq!(ocupante(&schedule, "2M12"), None);
+        assert_eq!(historico.undo(&mut schedule), Ok(None));
+        assert!(!historico.can_undo());
+
+        while historico.redo(&mut schedule).unwrap().is_some() {}
+        assert_eq!(ocupante(&schedule, "5T12").as_deref(), Some("Cálculo II"));
+        assert_eq!(historico.edits().len(), 3);
+    }
+
+    #[test]
+    fn new_edit_should_drop_redo_and_failed_edit_should_not_be_recorded() {
+        let mut schedule = Schedule::new();
+        let mut historico = Historico::new();
+
+        historico
+            .execute(&mut schedule, Edicao::Insert(disciplina("Cálculo I", "24M12")))
+            .unwrap();
+        historico.undo(&mut schedule).unwrap();
+        assert!(historico.can_redo());
+
+        historico
+            .execute(&mut schedule, Edicao::Insert(disciplina("Física I", "2M12")))
+            .unwrap();
+        assert!(!historico.can_redo());
+
+        let conflito = historico.execute(&mut schedule, Edicao::Move { disciplina: disciplina("Química", "3M12"), horarios: [SigaaTime::try_from("2M12").unwrap()].into() });
+        assert_eq!(conflito, Err(ScheduleError::DisciplineNotFoundToRemove));
+        assert_eq!(historico.edits(), [Edicao::Insert(disciplina("Física I", "2M12"))]);
+    }
+
+    #[test]
+    fn failed_move_should_keep_the_discipline_in_place() {
+        let mut schedule = Schedule::new();
+        let mut historico = Historico::new();
+
+        historico
+            .execute(&mut schedule, Edicao::Insert(disciplina("Cálculo I", "24M12")))
+            .unwrap();
+        historico
+            .execute(&mut schedule, Edicao::Insert(disciplina("Física I", "3M12")))
+            .unwrap();
+
+        let resultado = historico.execute(&mut schedule, Edicao::Move { disciplina: disciplina("Cálculo I", "24M12"), horarios: [SigaaTime::try_from("3M12").unwrap()].into() });
+
+        assert!(matches!(resultado, Err(ScheduleError::ConflictingDisciplines(_))));
+        assert_eq!(ocupante(&schedule, "2M12").as_deref(), Some("Cálculo I"));
+        assert_eq!(historico.edits().len(), 2);
+    }
+
+    #[test]
+    fn failed_move_should_leave_the_grid_exactly_as_it_was() {
+        let mut schedule = Schedule::with_policy(OverlapPolicy::Allow);
+        let mut historico = Historico::new();
+
+        historico
+            .execute(&mut schedule, Edicao::Insert(disciplina("Cálculo I", "24M12")))
+            .unwrap();
+        historico
+            .execute(&mut schedule, Edicao::Insert(disciplina("Física I", "2M12")))
+            .unwrap();
+        let antes = schedule.clone();
+
+        let resultado = historico.execute(&mut schedule, Edicao::Move { disciplina: disciplina("Cálculo I", "24M12"), horarios: [SigaaTime::try_from("1M12").unwrap()].into() });
+
+        assert_eq!(resultado, Err(ScheduleError::TimeNotFound(SigaaTime::try_from("1M12").unwrap())));
+        assert_eq!(ocupante(&schedule, "2M12").as_deref(), Some("Cálculo I"));
+        assert!(schedule == antes);
+    }
+
+    #[test]
+    fn history_should_be_saved_with_the_document_and_replayed() {
+        let mut schedule = Schedule::new();
+        let mut historico = Historico::new();
+
+        historico
+            .execute(&mut schedule, Edicao::Insert(disciplina("Cálculo I", "24M12")))
+            .unwrap();
+        historico
+            .execute(&mut schedule, Edicao::Insert(disciplina("Física I", "35M12")))
+            .unwrap();
+        historico.undo(&mut schedule).unwrap();
+
+        let mut documento = Documento::new(&schedule);
+        documento.historico = historico.clone();
+
+        let carregado = Documento::from_json(&documento.to_json()).unwrap();
+        assert_eq!(carregado.historico, historico);
+        assert!(carregado.historico.can_redo());
+        assert!(carregado
+            .historico
+            .replay(Schedule::new())
+            .unwrap()
+            .diff(&schedule)
+            .is_empty());
+
+        assert!(!Documento::new(&schedule)
+            .to_json()
+            .contains("historico"));
+    }
+
+    #[test]
+    fn history_should_keep_only_the_latest_edits() {
+        let mut schedule = Schedule::new();
+        let mut historico = Historico::new();
+        let calculo = disciplina("Cálculo I", "24M12");
+
+        for _ in 0..Historico::LIMITE {
+            historico
+                .execute(&mut schedule, Edicao::Insert(calculo.clone()))
+                .unwrap();
+            historico
+                .execute(&mut schedule, Edicao::Remove(calculo.clone()))
+                .unwrap();
+        }
+
+        assert_eq!(historico.edits().len(), Historico::LIMITE);
+        assert_eq!(historico.edits()[0], Edicao::Insert(calculo.clone()));
+
+        for _ in 0..Historico::LIMITE {
+            historico.undo(&mut schedule).unwrap().unwrap();
+        }
+        assert_eq!(historico.undo(&mut schedule), Ok(None));
+        assert_eq!(ocupante(&schedule, "2M12"), None);
+    }
+}
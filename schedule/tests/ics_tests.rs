@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod ics_tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use class::Disciplina;
+    use schedule::ics::{self, IcsErrorKind, RejectionReason};
+    use schedule::{FormatoGrade, OverlapPolicy, Schedule, Semestre};
+    use stf::{SigaaTime, TabelaHorarios};
+
+    fn data(ano: i32, mes: u32, dia: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(ano, mes, dia).unwrap()
+    }
+
+    fn data_hora(valor: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(valor, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn semestre() -> Semestre {
+        // 2025-03-10 é uma segunda-feira.
+        let mut semestre = Semestre::new(data(2025, 3, 10), data(2025, 7, 12)).unwrap();
+        semestre.add_feriado(data(2025, 4, 21));
+        semestre.add_feriado(data(2025, 5, 1));
+        semestre
+    }
+
+    #[test]
+    fn export_should_round_trip_through_the_parser() {
+        let mut schedule = Schedule::new();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap())
+            .unwrap();
+        let mut estruturas = Disciplina::new_stringify("Estruturas de Dados, Básico; Avançado", "5T3456").unwrap();
+        estruturas.local = Some("Setor II - A2".to_string());
+        estruturas
+            .set_local(SigaaTime::try_from("5T34").unwrap(), "LABIRAS 3")
+            .unwrap();
+        schedule.insert(estruturas).unwrap();
+
+        let calendario = schedule.to_ics(&semestre(), &TabelaHorarios::default());
+        let eventos = ics::parse(&calendario).unwrap();
+
+        assert_eq!(eventos.len(), 3);
+
+        let segunda = &eventos[0];
+        assert_eq!(segunda.titulo.as_deref(), Some("Cálculo I"));
+        assert_eq!(segunda.inicio, data_hora("2025-03-10 07:00"));
+        assert_eq!(segunda.fim, Some(data_hora("2025-03-10 08:40")));
+        assert_eq!(segunda.excecoes, vec![data_hora("2025-04-21 07:00")]);
+        assert_eq!(segunda.local, None);
+
+        let recorrencia = segunda.recorrencia.as_ref().unwrap();
+        assert_eq!(recorrencia.frequencia, "WEEKLY");
+        assert_eq!(recorrencia.ate, Some(data_hora("2025-07-12 00:00") + chrono::Duration::seconds(86399)));
+
+        let quinta = &eventos[2];
+        assert_eq!(quinta.titulo.as_deref(), Some("Estruturas de Dados, Básico; Avançado"));
+        assert_eq!(quinta.inicio, data_hora("2025-03-13 14:55"));
+        assert_eq!(quinta.fim, Some(data_hora("2025-03-13 18:30")));
+        assert_eq!(quinta.excecoes, vec![data_hora("2025-05-01 14:55")]);
+        assert_eq!(quinta.local.as_deref(), Some("LABIRAS 3"));
+    }
+
+    #[test]
+    fn export_should_give_overlapping_sections_their_own_uids() {
+        let mut schedule = Schedule::with_policy(OverlapPolicy::Allow);
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo I", "2M12 6T12").unwrap())
+            .unwrap();
+        let mut turma = Disciplina::new_stringify("Cálculo I", "2M1234").unwrap();
+        turma.codigo = Some("DIM0120".to_string());
+        turma.turma = Some("03".to_string());
+        schedule.insert(turma).unwrap();
+
+        let calendario = schedule.to_ics(&semestre(), &TabelaHorarios::default());
+        let uids: Vec<String> = ics::parse(&calendario)
+            .unwrap()
+            .into_iter()
+            .filter_map(|evento| evento.uid)
+            .collect();
+
+        assert_eq!(uids.len(), 5);
+        assert!(uids
+            .iter()
+            .all(|uid| uids.iter().filter(|outro| *outro == uid).count() == 1));
+        assert!(uids.iter().any(|uid| uid.contains("DIM0120-T03")));
+        assert_eq!(calendario, schedule.to_ics(&semestre(), &TabelaHorarios::default()));
+    }
+
+    #[test]
+    fn export_should_fold_long_lines() {
+        let mut schedule = Schedule::new();
+        let nome = "Tópicos Especiais em Engenharia de Software e Sistemas Distribuídos Avançados";
+        schedule
+            .insert(Disciplina::new_stringify(nome, "3N12").unwrap())
+            .unwrap();
+
+        let calendario = schedule.to_ics(&semestre(), &TabelaHorarios::default());
+
+        assert!(calendario
+            .split("\r\n")
+            .all(|linha| linha.len() <= 75));
+        assert_eq!(
+            ics::parse(&calendario).unwrap()[0]
+                .titulo
+                .as_deref(),
+            Some(nome)
+        );
+    }
+
+    #[test]
+    fn parse_should_read_duration_and_ignore_other_components() {
+        let calendario = "BEGIN:VCALENDAR\nBEGIN:VTIMEZONE\nTZID:America/Fortaleza\nEND:VTIMEZONE\nBEGIN:VEVENT\nDTSTART;TZID=America/Fortaleza:20250311T131500\nDURATION:PT1H40M\nRRULE:FREQ=WEEKLY;BYDAY=TU,TH\nBEGIN:VALARM\nTRIGGER:-PT10M\nEND:VALARM\nEND:VEVENT\nEND:VCALENDAR\n";
+
+        let eventos = ics::parse(calendario).unwrap();
+
+        assert_eq!(eventos[0].fim, Some(data_hora("2025-03-11 14:55")));
+        assert_eq!(
+            eventos[0]
+                .recorrencia
+                .as_ref()
+                .unwrap()
+                .dias
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn parse_should_report_malformed_files() {
+        let sem_fim = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20250310T070000\n";
+        let sem_inicio = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nSUMMARY:Aula\nEND:VEVENT\nEND:VCALENDAR\n";
+
+        assert_eq!(ics::parse(sem_fim).unwrap_err().kind, IcsErrorKind::UnexpectedEnd("VEVENT".to_string()));
+        assert_eq!(ics::parse(sem_inicio).unwrap_err().linha, 4);
+        assert_eq!(ics::parse("").unwrap_err().kind, IcsErrorKind::MissingCalendar);
+    }
+
+    #[test]
+    fn import_should_rebuild_an_exported_schedule() {
+        let calculo = Disciplina::new_stringify("Cálculo I", "24M12").unwrap();
+        let estruturas = Disciplina::new_stringify("Estruturas de Dados", "35T3456").unwrap();
+
+        let mut schedule = Schedule::new();
+        schedule.insert(calculo.clone()).unwrap();
+        schedule.insert(estruturas.clone()).unwrap();
+
+        let calendario = schedule.to_ics(&semestre(), &TabelaHorarios::default());
+        let importado = Schedule::from_ics(&calendario, &TabelaHorarios::default(), &FormatoGrade::default()).unwrap();
+
+        assert_eq!(importado.disciplinas, vec![calculo, estruturas]);
+        assert!(importado.rejeitados.is_empty());
+        assert!(importado.schedule == schedule);
+    }
+
+    #[test]
+    fn import_should_report_events_outside_sigaa_slots() {
+        let calendario = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\nSUMMARY:Cálculo I\r\nDTSTART:20250310T070000\r\nDTEND:20250310T084000\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,WE\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nSUMMARY:Monitoria\r\nDTSTART:20250310T071500\r\nDTEND:20250310T084000\r\nRRULE:FREQ=WEEKLY\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nSUMMARY:Laboratório\r\nDTSTART:20250311T145500\r\nDTEND:20250311T154500\r\nRRULE:FREQ=WEEKLY\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nSUMMARY:Culto\r\nDTSTART:20250316T070000\r\nDTEND:20250316T084000\r\nRRULE:FREQ=WEEKLY\r\nEND:VEVENT\r\n\
+BEGIN:VEVENT\r\nSUMMARY:Prova\r\nDTSTART:20250312T070000\r\nDTEND:20250312T084000\r\nEND:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let importado = Schedule::from_ics(calendario, &TabelaHorarios::default(), &FormatoGrade::default()).unwrap();
+        let motivos: Vec<(Option<&str>, &RejectionReason)> = importado
+            .rejeitados
+            .iter()
+            .map(|rejeitado| (rejeitado.titulo.as_deref(), &rejeitado.motivo))
+            .collect();
+
+        assert_eq!(importado.disciplinas, vec![Disciplina::new_stringify("Cálculo I", "24M12").unwrap()]);
+        assert_eq!(motivos.len(), 4);
+        assert!(matches!(motivos[0], (Some("Monitoria"), RejectionReason::NotAligned { .. })));
+        assert_eq!(motivos[1], (Some("Laboratório"), &RejectionReason::NotPaired));
+        assert_eq!(motivos[2], (Some("Culto"), &RejectionReason::UnsupportedDay(chrono::Weekday::Sun)));
+        assert_eq!(motivos[3], (Some("Prova"), &RejectionReason::NotWeekly));
+    }
+
+    #[test]
+    fn import_should_accept_sundays_in_a_full_week() {
+        let calendario = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\nSUMMARY:Culto\r\nDTSTART:20250316T070000\r\nDTEND:20250316T084000\r\nRRULE:FREQ=WEEKLY\r\nEND:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let importado = Schedule::from_ics(calendario, &TabelaHorarios::default(), &FormatoGrade::semana_completa()).unwrap();
+
+        assert_eq!(importado.disciplinas, vec![Disciplina::new_stringify("Culto", "1M12").unwrap()]);
+        assert!(importado.rejeitados.is_empty());
+        assert_eq!(importado.schedule.format(), &FormatoGrade::semana_completa());
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod pontuacao_tests {
+    use class::Disciplina;
+    use schedule::{Criterio, Oferta, Pesos, Schedule};
+
+    fn ocorrencias(schedule: &Schedule, criterio: Criterio) -> u32 {
+        schedule
+            .score(&Pesos::default())
+            .criterios
+            .iter()
+            .find(|item| item.criterio == criterio)
+            .unwrap()
+            .ocorrencias
+    }
+
+    #[test]
+    fn score_should_count_every_criterion() {
+        let mut schedule = Schedule::new();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Física I", "2T34").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Química", "7N12").unwrap())
+            .unwrap();
+
+        assert_eq!(ocorrencias(&schedule, Criterio::Janelas), 3);
+        assert_eq!(ocorrencias(&schedule, Criterio::Cedo), 2);
+        assert_eq!(ocorrencias(&schedule, Criterio::Sabado), 1);
+        assert_eq!(ocorrencias(&schedule, Criterio::Noite), 1);
+        assert_eq!(ocorrencias(&schedule, Criterio::DiaIsolado), 2);
+        assert_eq!(ocorrencias(&schedule, Criterio::DiaLivre), 3);
+
+        let pontuacao = schedule.score(&Pesos::default());
+        assert_eq!(pontuacao.total, -3.0 - 2.0 - 2.0 - 1.0 - 2.0 + 6.0);
+        assert_eq!(
+            pontuacao.total,
+            pontuacao
+                .criterios
+                .iter()
+                .map(|item| item.pontos)
+                .sum::<f64>()
+        );
+    }
+
+    #[test]
+    fn rank_should_follow_the_configured_weights() {
+        let ofertas = [Oferta::new("Cálculo I", &["24M12", "35N12"]).unwrap(), Oferta::new("Física I", &["24M34", "6M1234"]).unwrap()];
+        let candidatos = Schedule::generate(&ofertas);
+        assert_eq!(candidatos.len(), 4);
+
+        let padrao = Schedule::rank(&candidatos, &Pesos::default(), 2);
+        assert_eq!(padrao.len(), 2);
+        assert!(padrao[0].0 == &candidatos[0]);
+        assert!(padrao[0].1.total >= padrao[1].1.total);
+
+        let sem_cedo = Pesos { cedo: 10.0, dia_livre: 0.0, ..Pesos::default() };
+        let ranking = Schedule::rank(&candidatos, &sem_cedo, 4);
+        assert!(ranking[0].0 == &candidatos[2]);
+        assert!(ranking
+            .windows(2)
+            .all(|par| par[0].1.total >= par[1].1.total));
+    }
+}
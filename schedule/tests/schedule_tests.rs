@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod schedule_tests {
-    use class::Disciplina;
-    use schedule::{DisciplineWasFound, Schedule, ScheduleUnity};
-    use stf::{Dia, HorarioDiurno, SigaaTime, SigaaTimeErrors, Turno};
+    use class::{Atividade, Disciplina};
+    use schedule::{Conflict, DisciplineWasFound, OverlapPolicy, Schedule, ScheduleError, ScheduleUnity};
+    use stf::{Dia, HorarioDiurno, SigaaTime, SigaaTimeErrors, SigaaTimeSet, Turno};
 
     #[test]
     fn should_create_a_schedule_unity() {
@@ -66,4 +66,265 @@ mod schedule_tests {
         assert_eq!(schedule.insert(disciplina_1.clone()), Ok(()));
         assert_eq!(schedule.remove(disciplina_1.clone()), Ok(()));
     }
+
+    #[test]
+    fn insert_should_refuse_overlap_on_any_slot() {
+        let mut schedule = Schedule::new();
+
+        let fisica = Disciplina::new_stringify("Física", "4M12").unwrap();
+        let calculo = Disciplina::new_stringify("Cálculo", "246M12").unwrap();
+
+        schedule.insert(fisica.clone()).unwrap();
+
+        let conflict = Conflict { horario: SigaaTime::new_from_strings("4", "M12").unwrap(), disciplina: fisica.clone() };
+
+        assert_eq!(schedule.insert(calculo.clone()), Err(ScheduleError::ConflictingDisciplines(vec![conflict])));
+        assert_eq!(schedule.verify_availability(&calculo), DisciplineWasFound::DisciplineFound(fisica.clone()));
+        assert_eq!(schedule.get_from_str("2M12").unwrap().disciplina, None);
+        assert_eq!(schedule.get_from_str("4M12").unwrap().disciplina, Some(fisica));
+    }
+
+    #[test]
+    fn conflicts_should_list_every_overlapping_slot() {
+        let mut schedule = Schedule::new();
+
+        let fisica = Disciplina::new_stringify("Física", "4M12").unwrap();
+        let quimica = Disciplina::new_stringify("Química", "6M1234").unwrap();
+        let calculo = Disciplina::new_stringify("Cálculo", "246M12 6M34").unwrap();
+
+        schedule.insert(fisica.clone()).unwrap();
+        schedule.insert(quimica.clone()).unwrap();
+
+        let conflicts = schedule.conflicts(&calculo);
+        let horarios: Vec<String> = conflicts
+            .iter()
+            .map(|c| c.horario.to_string())
+            .collect();
+
+        assert_eq!(horarios, ["4M12", "6M12", "6M34"]);
+        assert_eq!(conflicts[0].disciplina, fisica);
+        assert_eq!(conflicts[1].disciplina, quimica);
+    }
+
+    #[test]
+    fn remove_should_not_clear_slots_of_other_disciplines() {
+        let mut schedule = Schedule::new();
+
+        let fisica = Disciplina::new_stringify("Física", "4M12").unwrap();
+        let calculo = Disciplina::new_stringify("Cálculo", "246M12").unwrap();
+
+        schedule.insert(fisica.clone()).unwrap();
+
+        assert!(matches!(schedule.remove(calculo), Err(ScheduleError::ConflictingDisciplines(_))));
+        assert_eq!(schedule.remove(Disciplina::new_stringify("Química", "3T12").unwrap()), Err(ScheduleError::DisciplineNotFoundToRemove));
+        assert_eq!(schedule.get_from_str("4M12").unwrap().disciplina, Some(fisica));
+    }
+
+    #[test]
+    fn warn_policy_should_insert_and_return_conflicts() {
+        let mut schedule = Schedule::with_policy(OverlapPolicy::Warn);
+
+        let teoria = Disciplina::new_stringify("Física", "24M12").unwrap();
+        let laboratorio = Disciplina::new_stringify("Física - Laboratório", "4M1234").unwrap();
+
+        schedule.insert(teoria.clone()).unwrap();
+        let avisos = schedule
+            .insert_with_warnings(laboratorio.clone())
+            .unwrap();
+
+        assert_eq!(avisos, vec![Conflict { horario: SigaaTime::new_from_strings("4", "M12").unwrap(), disciplina: teoria.clone() }]);
+
+        let unity = schedule.get_from_str("4M12").unwrap();
+        assert_eq!(unity.disciplina, Some(teoria.clone()));
+        assert_eq!(unity.sobrepostas, vec![laboratorio.clone()]);
+
+        let overlaps: Vec<String> = schedule
+            .overlaps()
+            .iter()
+            .map(|u| u.horario.to_string())
+            .collect();
+        assert_eq!(overlaps, ["4M12"]);
+
+        assert!(schedule.insert(laboratorio).is_err());
+    }
+
+    #[test]
+    fn removing_an_overlapped_discipline_should_keep_the_other() {
+        let mut schedule = Schedule::with_policy(OverlapPolicy::Allow);
+
+        let teoria = Disciplina::new_stringify("Física", "24M12").unwrap();
+        let laboratorio = Disciplina::new_stringify("Física - Laboratório", "4M12").unwrap();
+
+        schedule.insert(teoria.clone()).unwrap();
+        assert_eq!(schedule.insert_with_warnings(laboratorio.clone()), Ok(vec![]));
+
+        assert_eq!(schedule.remove(teoria), Ok(()));
+
+        let unity = schedule.get_from_str("4M12").unwrap();
+        assert_eq!(unity.disciplina, Some(laboratorio));
+        assert!(unity.sobrepostas.is_empty());
+        assert!(schedule.overlaps().is_empty());
+        assert_eq!(schedule.get_from_str("2M12").unwrap().disciplina, None);
+    }
+
+    #[test]
+    fn occupied_times_should_follow_insertions_and_removals() {
+        let mut schedule = Schedule::with_policy(OverlapPolicy::Allow);
+
+        let teoria = Disciplina::new_stringify("Física", "24M12").unwrap();
+        let laboratorio = Disciplina::new_stringify("Física - Laboratório", "4M1234").unwrap();
+
+        schedule.insert(teoria.clone()).unwrap();
+        schedule.insert(laboratorio.clone()).unwrap();
+        assert_eq!(schedule.occupied_times(), SigaaTimeSet::try_from("2M12 4M1234").unwrap());
+
+        schedule.remove(teoria.clone()).unwrap();
+        assert_eq!(schedule.occupied_times(), SigaaTimeSet::try_from("4M1234").unwrap());
+        assert!(schedule.has_conflicts(&teoria));
+        assert!(!schedule.has_conflicts(&Disciplina::new_stringify("Cálculo", "2M12").unwrap()));
+
+        schedule.remove(laboratorio).unwrap();
+        assert!(schedule.occupied_times().is_empty());
+        assert!(schedule.conflicts(&teoria).is_empty());
+    }
+
+    #[test]
+    fn should_list_rooms_per_day_and_filter_by_activity() {
+        let mut fisica = Disciplina::new_stringify("Física", "2M12 5M12 5T34").unwrap();
+        fisica.local = Some("Setor II - A2".to_string());
+        fisica
+            .set_local(SigaaTime::try_from("5T34").unwrap(), "Laboratório 3")
+            .unwrap();
+        fisica
+            .set_atividade(SigaaTime::try_from("5T34").unwrap(), Atividade::Laboratorio)
+            .unwrap();
+
+        let mut calculo = Disciplina::new_stringify("Cálculo", "2M34 5M34").unwrap();
+        calculo.local = Some("Setor II - A2".to_string());
+
+        let mut schedule = Schedule::new();
+        schedule.insert(fisica.clone()).unwrap();
+        schedule.insert(calculo).unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Estágio", "3N12").unwrap())
+            .unwrap();
+
+        let locais = schedule.rooms_by_day();
+        assert_eq!(locais.len(), 2);
+        assert_eq!(locais[&Dia::Segunda], ["Setor II - A2"]);
+        assert_eq!(locais[&Dia::Quinta], ["Setor II - A2", "Laboratório 3"]);
+
+        assert_eq!(schedule.by_activity(Atividade::Laboratorio), vec![(SigaaTime::try_from("5T34").unwrap(), &fisica)]);
+        assert_eq!(schedule.by_activity(Atividade::Teorica).len(), 5);
+
+        let unity = schedule.get_from_str("5T34").unwrap();
+        assert_eq!(unity.local(), Some("Laboratório 3"));
+        assert_eq!(unity.atividade(), Some(Atividade::Laboratorio));
+        assert_eq!(schedule.get_from_str("6T34").unwrap().atividade(), None);
+    }
+
+    #[test]
+    fn disciplines_should_come_from_the_grid() {
+        let mut schedule = Schedule::with_policy(OverlapPolicy::Allow);
+
+        let teoria = Disciplina::new_stringify("Física", "24M12").unwrap();
+        let laboratorio = Disciplina::new_stringify("Física", "4M1234").unwrap();
+        let calculo = Disciplina { codigo: Some("DIM0120".to_string()), ..Disciplina::new_stringify("Cálculo", "35T34").unwrap() };
+
+        schedule.insert(teoria.clone()).unwrap();
+        schedule.insert(laboratorio.clone()).unwrap();
+        schedule.insert(calculo.clone()).unwrap();
+
+        assert_eq!(schedule.disciplines(), [&teoria, &laboratorio, &calculo]);
+        assert_eq!(schedule.discipline_count(), 3);
+        assert_eq!(schedule.find_by_name("Física"), [&teoria, &laboratorio]);
+        assert_eq!(schedule.find_by_code("DIM0120"), [&calculo]);
+        assert!(schedule.contains_discipline(&laboratorio));
+
+        schedule.remove(teoria.clone()).unwrap();
+
+        assert_eq!(schedule.disciplines(), [&laboratorio, &calculo]);
+        assert_eq!(schedule.find_by_name("Física"), [&laboratorio]);
+        assert!(!schedule.contains_discipline(&teoria));
+        assert!(schedule.find_by_name("Química").is_empty());
+    }
+
+    fn abreviacao(schedule: &Schedule, horario: &str) -> String {
+        schedule
+            .get_from_str(horario)
+            .unwrap()
+            .disciplina
+            .as_ref()
+            .unwrap()
+            .abreviacao
+            .clone()
+    }
+
+    #[test]
+    fn should_keep_abbreviations_unique() {
+        let mut schedule = Schedule::new();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo Numérico", "2M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Circuitos Numéricos", "3M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Introdução à Computação", "4M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Introdução da Computação", "5M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo Numérico", "6M12").unwrap())
+            .unwrap();
+
+        assert_eq!(abreviacao(&schedule, "2M12"), "CN");
+        assert_eq!(abreviacao(&schedule, "3M12"), "CIN");
+        assert_eq!(abreviacao(&schedule, "4M12"), "IC");
+        assert_eq!(abreviacao(&schedule, "5M12"), "INC");
+        assert_eq!(abreviacao(&schedule, "6M12"), "CN");
+
+        assert_eq!(schedule.remove(Disciplina::new_stringify("Circuitos Numéricos", "3M12").unwrap()), Ok(()));
+    }
+
+    #[test]
+    fn should_apply_pinned_abbreviations() {
+        let mut schedule = Schedule::new();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo I", "2M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Física I", "3M12").unwrap())
+            .unwrap();
+
+        assert_eq!(schedule.pin_abbreviation("Cálculo I", "FI"), Err(ScheduleError::AbbreviationInUse("FI".to_string())));
+        assert_eq!(schedule.pin_abbreviation("Cálculo I", " "), Err(ScheduleError::EmptyAbbreviation));
+        assert_eq!(schedule.pin_abbreviation("Cálculo I", "CALC"), Ok(()));
+        assert_eq!(abreviacao(&schedule, "2M12"), "CALC");
+
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo I", "4M12").unwrap())
+            .unwrap();
+        assert_eq!(abreviacao(&schedule, "4M12"), "CALC");
+
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo Aplicado Lógico Computacional", "5M12").unwrap())
+            .unwrap();
+        assert_eq!(abreviacao(&schedule, "5M12"), "CAALC");
+
+        schedule
+            .insert(Disciplina::new_stringify("Xadrez", "6M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("X", "7M12").unwrap())
+            .unwrap();
+        assert_eq!(abreviacao(&schedule, "7M12"), "X2");
+
+        assert_eq!(schedule.unpin_abbreviation("Cálculo I"), Some("CALC".to_string()));
+        assert!(schedule.pinned_abbreviations().is_empty());
+        assert_eq!(abreviacao(&schedule, "2M12"), "CI");
+        assert_eq!(abreviacao(&schedule, "4M12"), "CI");
+        assert_eq!(schedule.unpin_abbreviation("Cálculo I"), None);
+    }
 }
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod serializacao_tests {
+    use class::Disciplina;
+    use schedule::{FormatoGrade, OverlapPolicy, Schedule, ScheduleData, ScheduleDataError, ScheduleError, ScheduleUnity};
+    use serde_json::{json, Value};
+    use stf::{Dia, SigaaTime, SigaaTimeSet, Turno};
+
+    /// Saída de `serde_json::to_string` de um `Schedule` da primeira versão, com Cálculo I em 24M12 e Física I em 35T34.
+    const V0_SCHEDULE: &str = include_str!("fixtures/documento/v0_schedule.json");
+
+    fn schedule() -> Schedule {
+        let mut schedule = Schedule::with_policy(OverlapPolicy::Allow);
+        schedule
+            .pin_abbreviation("Física I", "FIS")
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap())
+            .unwrap();
+        schedule
+            .insert(Disciplina::new_stringify("Física I", "4M12 5T34").unwrap())
+            .unwrap();
+        schedule
+    }
+
+    #[test]
+    fn should_write_each_discipline_once_without_the_grid() {
+        let valor = serde_json::to_value(schedule()).unwrap();
+
+        assert_eq!(
+            valor
+                .as_object()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            ["disciplinas", "fixadas", "politica"]
+        );
+        assert_eq!(valor["disciplinas"].as_array().unwrap().len(), 2);
+        assert_eq!(valor["disciplinas"][1]["sigaa_time"], json!(["4M12", "5T34"]));
+        assert_eq!(valor["politica"], "Allow");
+        assert_eq!(valor["fixadas"], json!({"Física I": "FIS"}));
+    }
+
+    #[test]
+    fn should_rebuild_the_same_schedule() {
+        let json = serde_json::to_string(&schedule()).unwrap();
+        let lido: Schedule = serde_json::from_str(&json).unwrap();
+
+        assert!(lido == schedule());
+        assert_eq!(lido.overlaps().len(), 1);
+        assert_eq!(
+            lido.get_from_str("5T34")
+                .unwrap()
+                .disciplina
+                .as_ref()
+                .unwrap()
+                .abreviacao,
+            "FIS"
+        );
+
+        let mut domingo = Schedule::with_format(FormatoGrade::new(&Dia::TODOS, &Turno::TODOS));
+        domingo
+            .insert(Disciplina::new_stringify("Monitoria", "1M12").unwrap())
+            .unwrap();
+
+        let lido: Schedule = serde_json::from_value(serde_json::to_value(&domingo).unwrap()).unwrap();
+        assert!(lido == domingo);
+    }
+
+    #[test]
+    fn should_reject_disciplines_that_do_not_fit() {
+        let mut dados = ScheduleData::from(schedule());
+        dados.politica = OverlapPolicy::Reject;
+
+        assert!(matches!(Schedule::try_from(dados.clone()), Err(ScheduleDataError::Schedule(ScheduleError::ConflictingDisciplines(_)))));
+
+        dados.politica = OverlapPolicy::Allow;
+        dados
+            .disciplinas
+            .push(Disciplina::new_stringify("Monitoria", "1M12").unwrap());
+        assert_eq!(Schedule::try_from(dados).err(), Some(ScheduleDataError::Schedule(ScheduleError::TimeNotFound(SigaaTime::try_from("1M12").unwrap()))));
+
+        let repetida = json!({"disciplinas": [], "fixadas": {"Cálculo I": "C", "Física I": "C"}});
+        let erro = serde_json::from_value::<Schedule>(repetida)
+            .err()
+            .unwrap();
+        assert!(erro.to_string().contains("AbbreviationInUse"));
+    }
+
+    #[test]
+    fn should_read_a_schedule_written_by_the_first_version() {
+        let lido: Schedule = serde_json::from_str(V0_SCHEDULE).unwrap();
+
+        let mut esperado = Schedule::new();
+        esperado
+            .insert(Disciplina::new_stringify("Cálculo I", "24M12").unwrap())
+            .unwrap();
+        esperado
+            .insert(Disciplina::new_stringify("Física I", "35T34").unwrap())
+            .unwrap();
+
+        assert!(lido == esperado);
+        assert!(serde_json::from_value::<Schedule>(serde_json::to_value(&lido).unwrap()).unwrap() == esperado);
+    }
+
+    #[test]
+    fn should_reject_an_old_grid_that_does_not_match_its_disciplines() {
+        let mut valor: Value = serde_json::from_str(V0_SCHEDULE).unwrap();
+        valor[0][2]["disciplina"] = Value::Null;
+
+        let grade: Vec<Vec<ScheduleUnity>> = serde_json::from_value(valor.clone()).unwrap();
+        assert_eq!(Schedule::try_from(grade).err(), Some(ScheduleDataError::InconsistentGrid(SigaaTime::try_from("4M12").unwrap())));
+
+        let erro = serde_json::from_value::<Schedule>(valor)
+            .err()
+            .unwrap();
+        assert!(erro.to_string().contains("4M12"));
+    }
+
+    #[test]
+    fn time_sets_should_be_written_as_sigaa_codes() {
+        let conjunto = SigaaTimeSet::try_from("2M12 4M12 6T34").unwrap();
+
+        assert_eq!(serde_json::to_value(conjunto).unwrap(), json!("24M12 6T34"));
+        assert_eq!(serde_json::from_value::<SigaaTimeSet>(json!("24M12 6T34")).unwrap(), conjunto);
+        assert_eq!(serde_json::from_value::<SigaaTimeSet>(json!(["2M12", "4M12", {"dia": "Sexta", "turno": {"Tarde": "Segundo"}}])).unwrap(), conjunto);
+        assert!(serde_json::from_value::<SigaaTimeSet>(json!("24M12 6T3")).is_err());
+
+        assert_eq!(serde_json::to_value(SigaaTimeSet::new()).unwrap(), json!(""));
+        assert_eq!(serde_json::from_value::<SigaaTimeSet>(json!("")).unwrap(), SigaaTimeSet::new());
+        assert_eq!(serde_json::from_value::<SigaaTimeSet>(json!("  ")).unwrap(), SigaaTimeSet::new());
+        assert_eq!(SigaaTimeSet::try_from(" ").unwrap(), SigaaTimeSet::new());
+        assert!(serde_json::from_value::<SigaaTimeSet>(serde_json::to_value(Schedule::new().occupied_times()).unwrap())
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod sigaa_tests {
+    use class::Disciplina;
+    use schedule::sigaa::{self, RowRejection, SigaaImportError};
+    use stf::ParseErrorKind;
+
+    const ATESTADO_HTML: &str = include_str!("fixtures/sigaa/atestado.html");
+    const TURMAS_TXT: &str = include_str!("fixtures/sigaa/turmas.txt");
+    const TURMAS_ESPACOS_TXT: &str = include_str!("fixtures/sigaa/turmas_espacos.txt");
+
+    #[test]
+    fn should_import_the_enrollment_html_page() {
+        let importado = sigaa::parse(ATESTADO_HTML).unwrap();
+        let matriculas = &importado.matriculas;
+
+        assert_eq!(matriculas.len(), 3);
+
+        assert_eq!(matriculas[0].codigo, "DIM0119");
+        assert_eq!(matriculas[0].nome, "ESTRUTURAS DE DADOS BÁSICAS I");
+        assert_eq!(matriculas[0].turma, "01");
+        assert_eq!(matriculas[0].local, "A308 - Setor IV");
+        assert_eq!(matriculas[0].horario, "35T34");
+        assert_eq!(
+            matriculas[0].disciplina.sigaa_time,
+            Disciplina::new_stringify("ESTRUTURAS DE DADOS BÁSICAS I", "35T34")
+                .unwrap()
+                .sigaa_time
+        );
+        assert_eq!(matriculas[0].disciplina.codigo.as_deref(), Some("DIM0119"));
+        assert_eq!(matriculas[0].disciplina.turma.as_deref(), Some("01"));
+        assert_eq!(matriculas[0].disciplina.local.as_deref(), Some("A308 - Setor IV"));
+
+        assert_eq!(matriculas[1].nome, "CÁLCULO DIFERENCIAL E INTEGRAL I");
+        assert_eq!(matriculas[1].local, "Setor II – Sala A2");
+        assert_eq!(matriculas[2].nome, "LINGUAGEM DE PROGRAMAÇÃO");
+        assert_eq!(matriculas[2].local, "Lab. 3 & 4");
+        assert_eq!(matriculas[2].disciplina.sigaa_time.len(), 2);
+    }
+
+    #[test]
+    fn should_report_rows_without_a_valid_time() {
+        let importado = sigaa::parse(ATESTADO_HTML).unwrap();
+        let rejeitadas = &importado.rejeitadas;
+
+        assert_eq!(rejeitadas.len(), 2);
+        assert_eq!(rejeitadas[0].celulas[0], "DIM0501");
+        assert_eq!(rejeitadas[0].motivo, RowRejection::MissingTime);
+        assert_eq!(rejeitadas[1].celulas[1], "FÍSICA EXPERIMENTAL");
+        assert!(matches!(&rejeitadas[1].motivo, RowRejection::InvalidTime(erro) if erro.kind == ParseErrorKind::PeriodosNotPaired));
+    }
+
+    #[test]
+    fn should_import_copied_text_with_code_and_name_in_one_column() {
+        let importado = sigaa::parse(TURMAS_TXT).unwrap();
+        let matriculas = &importado.matriculas;
+
+        assert_eq!(matriculas.len(), 3);
+        assert!(importado.rejeitadas.is_empty());
+        assert_eq!(matriculas[1].codigo, "MAT0311");
+        assert_eq!(matriculas[1].nome, "CÁLCULO DIFERENCIAL E INTEGRAL I");
+        assert_eq!(matriculas[1].turma, "03");
+        assert_eq!(matriculas[2].horario, "2N12 4N34");
+    }
+
+    #[test]
+    fn should_import_text_aligned_with_spaces() {
+        let importado = sigaa::parse(TURMAS_ESPACOS_TXT).unwrap();
+
+        assert_eq!(importado.matriculas.len(), 2);
+        assert_eq!(importado.matriculas[0].codigo, "DIM0119");
+        assert_eq!(
+            importado.matriculas[1]
+                .disciplina
+                .sigaa_time
+                .len(),
+            3
+        );
+        assert_eq!(importado.matriculas[1].local, "");
+        assert_eq!(importado.matriculas[1].disciplina.local, None);
+    }
+
+    #[test]
+    fn should_fail_without_a_header() {
+        assert_eq!(sigaa::parse("DIM0119\t35T34").unwrap_err(), SigaaImportError::MissingHeader);
+        assert_eq!(sigaa::parse("<table><tr><td>nada</td></tr></table>").unwrap_err(), SigaaImportError::MissingHeader);
+    }
+}
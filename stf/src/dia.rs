@@ -2,9 +2,28 @@ use super::Dia;
 use super::SigaaTimeErrors;
 use std::fmt::Display;
 
+impl Dia {
+    /// Todos os dias da semana, de domingo a sábado.
+    pub const TODOS: [Dia; 7] = [Dia::Domingo, Dia::Segunda, Dia::Terça, Dia::Quarta, Dia::Quinta, Dia::Sexta, Dia::Sabado];
+
+    /// Nome abreviado do dia, ex: `Seg`.
+    pub fn abreviacao(&self) -> &'static str {
+        match self {
+            Dia::Domingo => "Dom",
+            Dia::Segunda => "Seg",
+            Dia::Terça => "Ter",
+            Dia::Quarta => "Qua",
+            Dia::Quinta => "Qui",
+            Dia::Sexta => "Sex",
+            Dia::Sabado => "Sáb",
+        }
+    }
+}
+
 impl Display for Dia {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Dia::Domingo => write!(f, "1"),
             Dia::Segunda => write!(f, "2"),
             Dia::Terça => write!(f, "3"),
             Dia::Quarta => write!(f, "4"),
@@ -19,12 +38,13 @@ impl TryFrom<usize> for Dia {
     type Error = SigaaTimeErrors;
     fn try_from(value: usize) -> Result<Self, SigaaTimeErrors> {
         match value {
-            0 => Ok(Dia::Segunda),
-            1 => Ok(Dia::Terça),
-            2 => Ok(Dia::Quarta),
-            3 => Ok(Dia::Quinta),
-            4 => Ok(Dia::Sexta),
-            5 => Ok(Dia::Sabado),
+            0 => Ok(Dia::Domingo),
+            1 => Ok(Dia::Segunda),
+            2 => Ok(Dia::Terça),
+            3 => Ok(Dia::Quarta),
+            4 => Ok(Dia::Quinta),
+            5 => Ok(Dia::Sexta),
+            6 => Ok(Dia::Sabado),
             _ => Err(SigaaTimeErrors::InvalidUsizeToDay),
         }
     }
@@ -33,12 +53,13 @@ impl TryFrom<usize> for Dia {
 impl From<Dia> for usize {
     fn from(value: Dia) -> Self {
         match value {
-            Dia::Segunda => 0,
-            Dia::Terça => 1,
-            Dia::Quarta => 2,
-            Dia::Quinta => 3,
-            Dia::Sexta => 4,
-            Dia::Sabado => 5,
+            Dia::Domingo => 0,
+            Dia::Segunda => 1,
+            Dia::Terça => 2,
+            Dia::Quarta => 3,
+            Dia::Quinta => 4,
+            Dia::Sexta => 5,
+            Dia::Sabado => 6,
         }
     }
 }
@@ -47,6 +68,7 @@ impl TryFrom<&str> for Dia {
     type Error = SigaaTimeErrors;
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
+            "1" => Ok(Dia::Domingo),
             "2" => Ok(Dia::Segunda),
             "3" => Ok(Dia::Terça),
             "4" => Ok(Dia::Quarta),
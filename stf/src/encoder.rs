@@ -0,0 +1,344 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use super::{Dia, Periodo, SigaaCode, SigaaTime, Turno};
+
+impl SigaaCode {
+    /// Menor lista de códigos que representa exatamente os horários informados.
+    ///
+    /// Um código é o produto de um conjunto de dias por um conjunto de turnos, então a busca procura a
+    /// menor quantidade de produtos que cobre os horários sem incluir nenhum outro. Entre as listas com a
+    /// mesma quantidade de códigos, fica a com menos caracteres. A busca exata tem um limite de passos:
+    /// em grades muito fragmentadas, fica a melhor entre ela e uma cobertura gulosa, que pode ter um
+    /// código a mais que o mínimo. Os códigos saem na ordem do primeiro período
+    /// de cada um, e sempre voltam aos mesmos horários por `SigaaTime::parse_codes`.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{SigaaCode, SigaaTime};
+    ///
+    /// let horarios = SigaaTime::parse_codes("2M12 3M12 3T34 4T34").unwrap();
+    /// let codigos: Vec<String> = SigaaCode::encode(&horarios).iter().map(|c| c.to_string()).collect();
+    /// assert_eq!(codigos, ["23M12", "34T34"]);
+    /// ```
+    pub fn encode(horarios: &BTreeSet<SigaaTime>) -> Vec<SigaaCode> {
+        let grade = Grade::new(horarios);
+        let candidatos = grade.conceitos();
+        let guloso = enxugar(&grade.guloso(&candidatos));
+
+        let mut passos = 0;
+        let mut exata: Option<Vec<(u8, u8)>> = None;
+        for limite in 1..=guloso.len() {
+            let mut escolhidos = Vec::new();
+            grade.buscar(&candidatos, limite, 0, &mut escolhidos, &mut exata, &mut passos);
+
+            if exata.is_some() || passos > LIMITE_PASSOS {
+                break;
+            }
+        }
+
+        let melhor = match exata {
+            Some(exata) if (exata.len(), tamanho(&exata)) <= (guloso.len(), tamanho(&guloso)) => exata,
+            _ => guloso,
+        };
+
+        let mut codigos: Vec<SigaaCode> = melhor
+            .into_iter()
+            .map(|(dias, turnos)| codigo(dias, turnos))
+            .collect();
+        codigos.sort_by_key(|codigo| (codigo.periodos.first().copied(), codigo.dias.clone()));
+        codigos
+    }
+}
+
+impl SigaaTime {
+    /// Escreve os horários no formato do SIGAA, com a menor quantidade de códigos (veja `SigaaCode::encode`).
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::SigaaTime;
+    ///
+    /// let horarios = SigaaTime::parse_codes("2M12 4T34").unwrap();
+    /// assert_eq!(SigaaTime::format_codes(&horarios), "2M12 4T34");
+    ///
+    /// let horarios = SigaaTime::parse_codes("2M12 4M12 2M34 4M34 6N12").unwrap();
+    /// assert_eq!(SigaaTime::format_codes(&horarios), "24M1234 6N12");
+    /// ```
+    pub fn format_codes(horarios: &BTreeSet<SigaaTime>) -> String {
+        SigaaCode::encode(horarios)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Display for SigaaCode {
+    /// Dias seguidos de cada turno com seus períodos, ex: `24M12` ou `2M56T12`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for dia in &self.dias {
+            write!(f, "{}", dia)?;
+        }
+
+        let mut letra = None;
+        for periodo in &self.periodos {
+            if letra != Some(periodo.letra()) {
+                letra = Some(periodo.letra());
+                write!(f, "{}", periodo.letra())?;
+            }
+            write!(f, "{}", periodo.numero())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Passos da busca exata antes de ficar com a cobertura gulosa, para a escrita nunca travar.
+const LIMITE_PASSOS: usize = 10_000;
+
+/// Horários como uma máscara de dias (um bit por `Dia`) para cada turno.
+struct Grade {
+    colunas: [u8; 8],
+}
+
+impl Grade {
+    fn new(horarios: &BTreeSet<SigaaTime>) -> Grade {
+        let mut colunas = [0u8; 8];
+
+        for horario in horarios {
+            colunas[usize::from(horario.turno)] |= 1 << usize::from(horario.dia);
+        }
+
+        Grade { colunas }
+    }
+
+    /// Células ocupadas, como (máscara do dia, índice do turno).
+    fn celulas(&self) -> impl Iterator<Item = (u8, usize)> + '_ {
+        self.colunas
+            .iter()
+            .enumerate()
+            .flat_map(|(turno, &dias)| {
+                (0..7)
+                    .map(|dia| 1u8 << dia)
+                    .filter(move |dia| dias & dia != 0)
+                    .map(move |dia| (dia, turno))
+            })
+    }
+
+    /// Turnos em que todos os dias da máscara estão ocupados.
+    fn turnos_de(&self, dias: u8) -> u8 {
+        (0..8)
+            .filter(|&turno| self.colunas[turno] & dias == dias)
+            .fold(0, |turnos, turno| turnos | 1 << turno)
+    }
+
+    /// Dias ocupados em todos os turnos da máscara.
+    fn dias_de(&self, turnos: u8) -> u8 {
+        (0..8)
+            .filter(|turno| turnos & (1 << turno) != 0)
+            .fold(0x7f, |dias, turno| dias & self.colunas[turno])
+    }
+
+    /// Produtos maximais (dias x turnos) contidos nos horários: aumentar os dias ou os turnos de qualquer
+    /// um deles incluiria um horário que não existe. Toda cobertura mínima pode usar só esses.
+    fn conceitos(&self) -> Vec<(u8, u8)> {
+        let mut conceitos: Vec<(u8, u8)> = (1..=0x7fu8)
+            .map(|dias| self.turnos_de(dias))
+            .filter(|&turnos| turnos != 0)
+            .map(|turnos| (self.dias_de(turnos), turnos))
+            .collect();
+
+        conceitos.sort();
+        conceitos.dedup();
+        conceitos
+    }
+
+    /// Cobertura escolhendo, a cada passo, o produto que cobre mais horários ainda descobertos.
+    fn guloso(&self, candidatos: &[(u8, u8)]) -> Vec<(u8, u8)> {
+        let todos = self
+            .celulas()
+            .fold(0, |coberto, (dia, turno)| coberto | bit(dia, turno));
+        let mut coberto = 0u64;
+        let mut escolhidos = Vec::new();
+
+        while coberto != todos {
+            let Some(&produto) = candidatos
+                .iter()
+                .max_by_key(|(dias, turnos)| (mascara(*dias, *turnos) & !coberto).count_ones())
+            else {
+                break;
+            };
+
+            coberto |= mascara(produto.0, produto.1);
+            escolhidos.push(produto);
+        }
+
+        escolhidos
+    }
+
+    /// Busca em profundidade por coberturas com até `limite` produtos, guardando a de menos caracteres.
+    ///
+    /// Para depois de `LIMITE_PASSOS` chamadas, contadas em `passos` entre todas as profundidades.
+    fn buscar(&self, candidatos: &[(u8, u8)], limite: usize, coberto: u64, escolhidos: &mut Vec<(u8, u8)>, melhor: &mut Option<Vec<(u8, u8)>>, passos: &mut usize) {
+        *passos += 1;
+        if *passos > LIMITE_PASSOS {
+            return;
+        }
+
+        let faltando = self
+            .celulas()
+            .find(|(dia, turno)| coberto & bit(*dia, *turno) == 0);
+
+        let Some((dia, turno)) = faltando else {
+            let cobertura = enxugar(escolhidos);
+            if melhor
+                .as_ref()
+                .is_none_or(|melhor| (tamanho(&cobertura), &cobertura) < (tamanho(melhor), melhor))
+            {
+                *melhor = Some(cobertura);
+            }
+            return;
+        };
+
+        if escolhidos.len() == limite {
+            return;
+        }
+
+        for &(dias, turnos) in candidatos
+            .iter()
+            .filter(|(dias, turnos)| dias & dia != 0 && turnos & (1 << turno) != 0)
+        {
+            escolhidos.push((dias, turnos));
+            self.buscar(candidatos, limite, coberto | mascara(dias, turnos), escolhidos, melhor, passos);
+            escolhidos.pop();
+        }
+    }
+}
+
+/// Tira de cada produto os turnos e depois os dias que os outros produtos já cobrem, encurtando o texto.
+fn enxugar(produtos: &[(u8, u8)]) -> Vec<(u8, u8)> {
+    let mut produtos = produtos.to_vec();
+    produtos.sort();
+
+    for indice in 0..produtos.len() {
+        let outros = |produtos: &[(u8, u8)]| -> u64 {
+            produtos
+                .iter()
+                .enumerate()
+                .filter(|(outro, _)| *outro != indice)
+                .fold(0, |coberto, (_, (dias, turnos))| coberto | mascara(*dias, *turnos))
+        };
+
+        for turno in (0..8).map(|turno| 1u8 << turno) {
+            let (dias, turnos) = produtos[indice];
+            if turnos & turno != 0 && turnos != turno && mascara(dias, turno) & !outros(&produtos) == 0 {
+                produtos[indice].1 &= !turno;
+            }
+        }
+
+        for dia in (0..7).map(|dia| 1u8 << dia) {
+            let (dias, turnos) = produtos[indice];
+            if dias & dia != 0 && dias != dia && mascara(dia, turnos) & !outros(&produtos) == 0 {
+                produtos[indice].0 &= !dia;
+            }
+        }
+    }
+
+    produtos.sort();
+    produtos
+}
+
+/// Quantidade de caracteres dos códigos escritos, contando os espaços entre eles.
+fn tamanho(produtos: &[(u8, u8)]) -> usize {
+    produtos
+        .iter()
+        .map(|&(dias, turnos)| codigo(dias, turnos).to_string().chars().count() + 1)
+        .sum()
+}
+
+fn bit(dia: u8, turno: usize) -> u64 {
+    (dia as u64) << (turno * 8)
+}
+
+fn mascara(dias: u8, turnos: u8) -> u64 {
+    (0..8)
+        .filter(|turno| turnos & (1 << turno) != 0)
+        .fold(0, |mascara, turno| mascara | bit(dias, turno))
+}
+
+fn codigo(dias: u8, turnos: u8) -> SigaaCode {
+    let dias: BTreeSet<Dia> = Dia::TODOS
+        .into_iter()
+        .filter(|dia| dias & (1 << usize::from(*dia)) != 0)
+        .collect();
+    let periodos: BTreeSet<Periodo> = Turno::TODOS
+        .into_iter()
+        .filter(|turno| turnos & (1 << usize::from(*turno)) != 0)
+        .flat_map(|turno| turno.periodos())
+        .collect();
+
+    SigaaCode::new(dias, periodos)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn should_not_merge_days_and_turnos_that_do_not_meet() {
+        let formatar = |codigos: &str| SigaaTime::format_codes(&SigaaTime::parse_codes(codigos).unwrap());
+
+        assert_eq!(formatar("2M12 4T34"), "2M12 4T34");
+        assert_eq!(formatar("2M12 4M12 2M34 4M34 6N12"), "24M1234 6N12");
+        assert_eq!(formatar("2M56T12 4M56"), "24M56 2T12");
+    }
+
+    #[test]
+    fn format_codes_should_parse_back_to_the_same_times() {
+        let todos: Vec<SigaaTime> = SigaaTime::parse_codes("1234567M123456T123456N1234")
+            .unwrap()
+            .into_iter()
+            .collect();
+        let mut semente: u64 = 0x5eed;
+
+        for _ in 0..500 {
+            let mut horarios = BTreeSet::new();
+            for horario in &todos {
+                semente = semente
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                if semente >> 62 == 0 {
+                    horarios.insert(*horario);
+                }
+            }
+
+            let codigos = SigaaTime::format_codes(&horarios);
+            match horarios.is_empty() {
+                true => assert_eq!(codigos, ""),
+                false => assert_eq!(SigaaTime::parse_codes(&codigos).unwrap(), horarios, "{codigos}"),
+            }
+        }
+    }
+
+    #[test]
+    fn format_codes_should_finish_quickly_on_dense_times() {
+        let todos: Vec<SigaaTime> = SigaaTime::parse_codes("1234567M123456T123456N1234")
+            .unwrap()
+            .into_iter()
+            .collect();
+        let sem_diagonal: BTreeSet<SigaaTime> = todos
+            .iter()
+            .filter(|horario| usize::from(horario.dia) != usize::from(horario.turno))
+            .copied()
+            .collect();
+
+        let inicio = std::time::Instant::now();
+        let codigos = SigaaTime::format_codes(&sem_diagonal);
+
+        assert!(inicio.elapsed() < std::time::Duration::from_secs(1), "{:?}", inicio.elapsed());
+        assert_eq!(SigaaTime::parse_codes(&codigos).unwrap(), sem_diagonal);
+    }
+}
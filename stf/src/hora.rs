@@ -0,0 +1,280 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use super::{Bloco, Dia, Hora, Periodo, SigaaSlot, SigaaTime, SigaaTimeErrors, TabelaHorarios};
+
+impl Hora {
+    /// Cria uma nova hora do relógio, validando horas (0 a 23) e minutos (0 a 59).
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::Hora;
+    /// assert_eq!(Hora::new(7, 50).unwrap().to_string(), "07:50");
+    /// assert!(Hora::new(24, 0).is_err());
+    /// ```
+    pub fn new(hora: u8, minuto: u8) -> Result<Hora, SigaaTimeErrors> {
+        match hora < 24 && minuto < 60 {
+            true => Ok(Hora { hora, minuto }),
+            false => Err(SigaaTimeErrors::InvalidHora),
+        }
+    }
+
+    /// Minutos desde a meia-noite.
+    pub fn minutos(&self) -> u16 {
+        self.hora as u16 * 60 + self.minuto as u16
+    }
+
+    /// Minutos entre esta hora e uma hora posterior (zero se `outra` vier antes).
+    pub fn minutos_ate(&self, outra: Hora) -> u16 {
+        outra.minutos().saturating_sub(self.minutos())
+    }
+}
+
+impl Display for Hora {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hora, self.minuto)
+    }
+}
+
+impl TryFrom<&str> for Hora {
+    type Error = SigaaTimeErrors;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (hora, minuto) = value
+            .split_once(':')
+            .ok_or(SigaaTimeErrors::InvalidHora)?;
+
+        if minuto.len() != 2 {
+            return Err(SigaaTimeErrors::InvalidHora);
+        }
+
+        match (hora.parse(), minuto.parse()) {
+            (Ok(hora), Ok(minuto)) => Hora::new(hora, minuto),
+            _ => Err(SigaaTimeErrors::InvalidHora),
+        }
+    }
+}
+
+impl TabelaHorarios {
+    /// Cria uma tabela com o início e fim de cada período, na ordem de `Periodo::TODOS`.
+    ///
+    /// Retorna `SigaaTimeErrors::InvalidIntervaloHora` se algum período terminar antes de começar.
+    pub fn new(periodos: [(Hora, Hora); 16]) -> Result<TabelaHorarios, SigaaTimeErrors> {
+        match periodos.iter().all(|(inicio, fim)| inicio < fim) {
+            true => Ok(TabelaHorarios { periodos }),
+            false => Err(SigaaTimeErrors::InvalidIntervaloHora),
+        }
+    }
+
+    /// Tabela de horários padrão da UFRN.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{Periodo, TabelaHorarios};
+    /// let tabela = TabelaHorarios::ufrn();
+    /// assert_eq!(tabela.inicio(Periodo::M1).to_string(), "07:00");
+    /// assert_eq!(tabela.fim(Periodo::N4).to_string(), "22:15");
+    /// ```
+    pub fn ufrn() -> TabelaHorarios {
+        #[rustfmt::skip]
+        let horarios = [
+            ((7, 0), (7, 50)), ((7, 50), (8, 40)), ((8, 55), (9, 45)), ((9, 45), (10, 35)), ((10, 50), (11, 40)), ((11, 40), (12, 30)),
+            ((13, 0), (13, 50)), ((13, 50), (14, 40)), ((14, 55), (15, 45)), ((15, 45), (16, 35)), ((16, 50), (17, 40)), ((17, 40), (18, 30)),
+            ((18, 45), (19, 35)), ((19, 35), (20, 25)), ((20, 35), (21, 25)), ((21, 25), (22, 15)),
+        ];
+
+        TabelaHorarios { periodos: horarios.map(|((h1, m1), (h2, m2))| (Hora { hora: h1, minuto: m1 }, Hora { hora: h2, minuto: m2 })) }
+    }
+
+    /// Altera o início e o fim de um período específico.
+    pub fn definir(&mut self, periodo: Periodo, inicio: Hora, fim: Hora) -> Result<(), SigaaTimeErrors> {
+        if inicio >= fim {
+            return Err(SigaaTimeErrors::InvalidIntervaloHora);
+        }
+
+        self.periodos[usize::from(periodo)] = (inicio, fim);
+        Ok(())
+    }
+
+    /// Hora de início de um período.
+    pub fn inicio(&self, periodo: Periodo) -> Hora {
+        self.periodos[usize::from(periodo)].0
+    }
+
+    /// Hora de fim de um período.
+    pub fn fim(&self, periodo: Periodo) -> Hora {
+        self.periodos[usize::from(periodo)].1
+    }
+
+    /// Duração de um período em minutos.
+    pub fn duracao_minutos(&self, periodo: Periodo) -> u16 {
+        self.inicio(periodo)
+            .minutos_ate(self.fim(periodo))
+    }
+
+    /// Soma da duração de todos os períodos do conjunto, em minutos.
+    pub fn duracao_total_minutos(&self, slots: &BTreeSet<SigaaSlot>) -> u32 {
+        slots
+            .iter()
+            .map(|slot| self.duracao_minutos(slot.periodo) as u32)
+            .sum()
+    }
+
+    /// Períodos seguidos de um mesmo turno que cobrem exatamente o intervalo `inicio..fim`.
+    ///
+    /// Retorna `None` se o intervalo não começar e terminar junto com algum período da tabela.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{Hora, Periodo, TabelaHorarios};
+    /// let tabela = TabelaHorarios::ufrn();
+    /// let periodos = tabela.periodos_entre(Hora::new(14, 55).unwrap(), Hora::new(16, 35).unwrap());
+    /// assert_eq!(periodos, Some(vec![Periodo::T3, Periodo::T4]));
+    /// assert_eq!(tabela.periodos_entre(Hora::new(14, 0).unwrap(), Hora::new(16, 35).unwrap()), None);
+    /// ```
+    pub fn periodos_entre(&self, inicio: Hora, fim: Hora) -> Option<Vec<Periodo>> {
+        let primeiro = Periodo::TODOS
+            .into_iter()
+            .find(|periodo| self.inicio(*periodo) == inicio)?;
+
+        let mut periodos = Vec::new();
+        for periodo in Periodo::TODOS[usize::from(primeiro)..]
+            .iter()
+            .take_while(|periodo| periodo.letra() == primeiro.letra())
+        {
+            periodos.push(*periodo);
+
+            if self.fim(*periodo) == fim {
+                return Some(periodos);
+            }
+        }
+
+        None
+    }
+
+    /// Agrupa os períodos em blocos de aula contínuos.
+    ///
+    /// Períodos seguidos do mesmo turno formam um único bloco, mesmo com o intervalo curto entre
+    /// eles (ex: `2M1234` é um bloco das 07:00 às 10:35).
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{SigaaSlot, TabelaHorarios};
+    /// let tabela = TabelaHorarios::ufrn();
+    /// let slots = SigaaSlot::parse_codes("2M12 2T34").unwrap();
+    /// let blocos: Vec<String> = tabela.blocos(&slots).iter().map(|b| b.to_string()).collect();
+    /// assert_eq!(blocos, ["2 07:00-08:40", "2 14:55-16:35"]);
+    /// ```
+    pub fn blocos(&self, slots: &BTreeSet<SigaaSlot>) -> Vec<Bloco> {
+        let mut blocos: Vec<Bloco> = Vec::new();
+        let mut anterior: Option<SigaaSlot> = None;
+
+        for slot in slots {
+            let continua = anterior.is_some_and(|anterior| anterior.dia == slot.dia && anterior.periodo.letra() == slot.periodo.letra() && usize::from(anterior.periodo) + 1 == usize::from(slot.periodo));
+
+            match blocos.last_mut() {
+                Some(bloco) if continua => bloco.fim = self.fim(slot.periodo),
+                _ => blocos.push(Bloco::new(slot.dia, self.inicio(slot.periodo), self.fim(slot.periodo))),
+            }
+
+            anterior = Some(*slot);
+        }
+
+        blocos
+    }
+
+    /// Janelas (tempo livre) entre blocos de aula consecutivos no mesmo dia.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{SigaaSlot, TabelaHorarios};
+    /// let tabela = TabelaHorarios::ufrn();
+    /// let slots = SigaaSlot::parse_codes("2M12 2T34").unwrap();
+    /// let janelas = tabela.janelas(&slots);
+    /// assert_eq!(janelas.len(), 1);
+    /// assert_eq!(janelas[0].duracao_minutos(), 375);
+    /// ```
+    pub fn janelas(&self, slots: &BTreeSet<SigaaSlot>) -> Vec<Bloco> {
+        self.blocos(slots)
+            .windows(2)
+            .filter(|par| par[0].dia == par[1].dia)
+            .map(|par| Bloco::new(par[0].dia, par[0].fim, par[1].inicio))
+            .collect()
+    }
+}
+
+impl Default for TabelaHorarios {
+    fn default() -> Self {
+        Self::ufrn()
+    }
+}
+
+impl Bloco {
+    pub fn new(dia: Dia, inicio: Hora, fim: Hora) -> Bloco {
+        Bloco { dia, inicio, fim }
+    }
+
+    /// Duração do bloco em minutos.
+    pub fn duracao_minutos(&self) -> u16 {
+        self.inicio.minutos_ate(self.fim)
+    }
+}
+
+impl Display for Bloco {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}-{}", self.dia, self.inicio, self.fim)
+    }
+}
+
+impl SigaaSlot {
+    /// Hora de início do período, segundo a tabela.
+    pub fn inicio(&self, tabela: &TabelaHorarios) -> Hora {
+        tabela.inicio(self.periodo)
+    }
+
+    /// Hora de fim do período, segundo a tabela.
+    pub fn fim(&self, tabela: &TabelaHorarios) -> Hora {
+        tabela.fim(self.periodo)
+    }
+
+    /// Duração do período em minutos.
+    pub fn duracao_minutos(&self, tabela: &TabelaHorarios) -> u16 {
+        tabela.duracao_minutos(self.periodo)
+    }
+}
+
+impl SigaaTime {
+    /// Hora de início do horário (início do primeiro período do par).
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{SigaaTime, TabelaHorarios};
+    /// let tabela = TabelaHorarios::default();
+    /// let sigaa_time = SigaaTime::try_from("2M12").unwrap();
+    /// assert_eq!(sigaa_time.inicio(&tabela).to_string(), "07:00");
+    /// assert_eq!(sigaa_time.fim(&tabela).to_string(), "08:40");
+    /// assert_eq!(sigaa_time.duracao_minutos(&tabela), 100);
+    /// ```
+    pub fn inicio(&self, tabela: &TabelaHorarios) -> Hora {
+        tabela.inicio(self.turno.periodos()[0])
+    }
+
+    /// Hora de fim do horário (fim do segundo período do par).
+    pub fn fim(&self, tabela: &TabelaHorarios) -> Hora {
+        tabela.fim(self.turno.periodos()[1])
+    }
+
+    /// Duração do horário em minutos, somando os dois períodos.
+    pub fn duracao_minutos(&self, tabela: &TabelaHorarios) -> u16 {
+        self.slots()
+            .iter()
+            .map(|slot| slot.duracao_minutos(tabela))
+            .sum()
+    }
+}
@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::ops::Range;
 
-/// Dias da semana utilizados nos horários.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// Dias da semana utilizados nos horários, na ordem dos códigos do SIGAA (domingo é `1`, sábado é `7`).
+///
+/// É serializado como o código (`"3"`), e também lê o nome do dia salvo pelas versões antigas (`"Terça"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Dia {
+    Domingo,
     Segunda,
     Terça,
     Quarta,
@@ -12,7 +17,9 @@ pub enum Dia {
 }
 
 /// Turnos do dia para horários
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// É serializado como o código (`"T34"`), e também lê o formato das versões antigas (`{"Tarde": "Segundo"}`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Turno {
     /// Turnos diurnos (manhã e tarde). Cada um tem três possibilidades:
     ///
@@ -41,20 +48,143 @@ pub enum HorarioNoturno {
     Segundo,
 }
 
+/// Período individual de aula do SIGAA.
+///
+/// Os turnos diurnos têm seis períodos cada (M1..M6, T1..T6) e o turno noturno tem quatro (N1..N4).
+/// Um `Turno` corresponde sempre a um par desses períodos, ex: `M12` = `M1` + `M2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Periodo {
+    M1,
+    M2,
+    M3,
+    M4,
+    M5,
+    M6,
+    T1,
+    T2,
+    T3,
+    T4,
+    T5,
+    T6,
+    N1,
+    N2,
+    N3,
+    N4,
+}
+
+/// Representa um único período de aula em um dia.
+///
+/// Exemplo:
+/// 2M1, 3T4, 6N3
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SigaaSlot {
+    pub dia: Dia,
+    pub periodo: Periodo,
+}
+
 /// Representa um horário específico em um dia e turno.
 ///
 /// Exemplo:
 /// 2T56 , 4M12, 6N34
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// É serializado como o código do SIGAA (`"3T34"`). O objeto das versões antigas
+/// (`{"dia": "Terça", "turno": {"Tarde": "Segundo"}}`) continua sendo aceito na leitura.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SigaaTime {
     pub dia: Dia,
     pub turno: Turno,
 }
 
+/// Conjunto de horários guardado como uma máscara de bits, com um bit para cada dia e turno da semana.
+///
+/// Pertinência, união, interseção, diferença e complemento custam uma operação sobre um `u64`, e a iteração
+/// segue a ordem de `SigaaTime`. É lido e escrito como códigos do SIGAA, ex: `24M12 6T34`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SigaaTimeSet {
+    bits: u64,
+}
+
+/// Iterador sobre os horários de um `SigaaTimeSet`, na ordem de `SigaaTime`.
+#[derive(Debug, Clone)]
+pub struct SigaaTimeSetIter {
+    bits: u64,
+}
+
+/// Um código de horário do SIGAA, ex: `24M12`, `35T34` ou `2M56T12`.
+///
+/// O código representa o produto entre os dias e os períodos: `24M12` são os períodos M1 e M2
+/// tanto na segunda quanto na quarta. Um horário completo pode ter vários códigos separados
+/// por espaço, como `24M12 6T34`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SigaaCode {
+    pub dias: BTreeSet<Dia>,
+    pub periodos: BTreeSet<Periodo>,
+}
+
+/// Erro de leitura de um código de horário, apontando o trecho problemático da entrada.
+///
+/// O `span` é um intervalo de índices de caracteres (não de bytes) da string original.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub span: Range<usize>,
+}
+
+/// Tipos de erro encontrados ao ler um código de horário.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParseErrorKind {
+    /// A entrada não contém nenhum código.
+    Empty,
+    /// O código não começa com um dia.
+    MissingDia,
+    /// Caractere que não é um dia válido (1 a 7).
+    InvalidDia(char),
+    /// Dia repetido dentro do mesmo código.
+    DuplicatedDia(Dia),
+    /// Faltou a letra do turno depois dos dias.
+    MissingTurno,
+    /// Caractere que não é um turno válido (M, T ou N).
+    InvalidTurno(char),
+    /// Faltaram os números dos períodos depois do turno.
+    MissingPeriodo,
+    /// Número que não é um período válido para o turno.
+    InvalidPeriodo(char),
+    /// Período repetido dentro do mesmo código.
+    DuplicatedPeriodo(Periodo),
+    /// Caractere inesperado no final de um código.
+    UnexpectedChar(char),
+    /// Os períodos não fecham os pares de um `Turno` e não cabem em `SigaaTime`.
+    PeriodosNotPaired,
+}
+
+/// Hora do relógio, com precisão de minutos (ex: `07:00`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Hora {
+    pub hora: u8,
+    pub minuto: u8,
+}
+
+/// Tabela que associa cada `Periodo` ao seu horário de início e fim no relógio.
+///
+/// O padrão (`TabelaHorarios::default()`) segue os horários da UFRN, mas cada instituição
+/// pode montar a sua própria tabela.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TabelaHorarios {
+    periodos: [(Hora, Hora); 16],
+}
+
+/// Trecho contínuo de tempo em um dia, usado tanto para aulas quanto para janelas entre elas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Bloco {
+    pub dia: Dia,
+    pub inicio: Hora,
+    pub fim: Hora,
+}
+
 /// Erros relacionados ao `SigaaTime`.
 ///
 /// Estes erros cobrem problemas de conversão e formatação para `SigaaTime`.
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SigaaTimeErrors {
     /// Erro ao converter um `usize` para um dia.
     InvalidUsizeToDay,
@@ -68,8 +198,27 @@ pub enum SigaaTimeErrors {
     InvalidStringToSigaaTime,
     /// Erro ao converter uma string para um horário.
     InvalidStringToHorario,
+    /// Erro ao converter um `usize` para um período.
+    InvalidUsizeToPeriodo,
+    /// Erro ao converter uma string para um período.
+    InvalidStringToPeriodo,
+    /// Erro ao converter uma string para um `SigaaSlot`.
+    InvalidStringToSigaaSlot,
+    /// Os períodos não formam um dos pares de `Turno` (ex: `M23` ou `T4` sozinho).
+    PeriodosNotPaired,
+    /// Hora do relógio inválida (ex: `25:00` ou `7h`).
+    InvalidHora,
+    /// Período da tabela de horários termina antes de começar.
+    InvalidIntervaloHora,
 }
 
 mod dia;
+mod encoder;
+mod hora;
+mod parser;
+mod periodo;
+mod serializacao;
+mod sigaa_slot;
 mod sigaa_time;
+mod sigaa_time_set;
 mod turno;
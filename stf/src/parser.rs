@@ -0,0 +1,244 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::ops::Range;
+
+use super::{Dia, ParseError, ParseErrorKind, Periodo, SigaaCode, SigaaSlot, SigaaTime};
+
+impl SigaaCode {
+    /// Cria um novo código a partir dos dias e períodos.
+    pub fn new(dias: BTreeSet<Dia>, periodos: BTreeSet<Periodo>) -> SigaaCode {
+        SigaaCode { dias, periodos }
+    }
+
+    /// Expande o código em todos os períodos que ele representa.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::SigaaCode;
+    /// let code = SigaaCode::try_from("35M2").unwrap();
+    /// let slots: Vec<String> = code.slots().iter().map(|s| s.to_string()).collect();
+    /// assert_eq!(slots, ["3M2", "5M2"]);
+    /// ```
+    pub fn slots(&self) -> BTreeSet<SigaaSlot> {
+        self.dias
+            .iter()
+            .flat_map(|&dia| {
+                self.periodos
+                    .iter()
+                    .map(move |&periodo| SigaaSlot::new(dia, periodo))
+            })
+            .collect()
+    }
+
+    /// Lê todos os códigos de uma string, separados por espaços.
+    ///
+    /// Aceita a gramática completa do SIGAA: um ou mais dias (1 a 7), seguidos de um ou mais grupos
+    /// de turno (`M`, `T` ou `N`) com seus períodos, ex: `24M12 6T34`, `35M23`, `2M56T12`.
+    /// Nunca entra em pânico; em caso de erro retorna um `ParseError` com o trecho problemático.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{ParseErrorKind, SigaaCode};
+    /// assert_eq!(SigaaCode::parse_all("24M12 6T34").unwrap().len(), 2);
+    ///
+    /// let erro = SigaaCode::parse_all("24M12 8T34").unwrap_err();
+    /// assert_eq!(erro.kind, ParseErrorKind::InvalidDia('8'));
+    /// assert_eq!(erro.span, 6..7);
+    /// ```
+    pub fn parse_all(input: &str) -> Result<Vec<SigaaCode>, ParseError> {
+        Ok(parse_spanned(input)?
+            .into_iter()
+            .map(|(code, _)| code)
+            .collect())
+    }
+}
+
+impl TryFrom<&str> for SigaaCode {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut codes = parse_spanned(value)?;
+
+        match codes.len() {
+            1 => Ok(codes.remove(0).0),
+            _ => {
+                let fim = codes[0].1.end;
+                let separador = value.chars().nth(fim).unwrap_or(' ');
+                Err(ParseError::new(ParseErrorKind::UnexpectedChar(separador), fim..fim + 1))
+            }
+        }
+    }
+}
+
+impl SigaaSlot {
+    /// Lê uma string de horário completa e retorna todos os períodos que ela ocupa.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::SigaaSlot;
+    /// let slots = SigaaSlot::parse_codes("2T4 4N123").unwrap();
+    /// assert_eq!(slots.len(), 4);
+    /// ```
+    pub fn parse_codes(input: &str) -> Result<BTreeSet<SigaaSlot>, ParseError> {
+        Ok(parse_spanned(input)?
+            .iter()
+            .flat_map(|(code, _)| code.slots())
+            .collect())
+    }
+}
+
+impl SigaaTime {
+    /// Lê uma string de horário completa e agrupa os períodos em horários pareados.
+    ///
+    /// Códigos cujos períodos não fecham pares (ex: `2T4` ou `35M23`) resultam em
+    /// `ParseErrorKind::PeriodosNotPaired`, apontando para o código inteiro.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{ParseErrorKind, SigaaTime};
+    /// assert_eq!(SigaaTime::parse_codes("24M12 6T34").unwrap().len(), 3);
+    ///
+    /// let erro = SigaaTime::parse_codes("24M12 6T3").unwrap_err();
+    /// assert_eq!(erro.kind, ParseErrorKind::PeriodosNotPaired);
+    /// assert_eq!(erro.span, 6..9);
+    /// ```
+    pub fn parse_codes(input: &str) -> Result<BTreeSet<SigaaTime>, ParseError> {
+        let mut sigaa_times = BTreeSet::new();
+
+        for (code, span) in parse_spanned(input)? {
+            let paired = SigaaTime::from_slots(&code.slots()).map_err(|_| ParseError::new(ParseErrorKind::PeriodosNotPaired, span))?;
+            sigaa_times.extend(paired);
+        }
+
+        Ok(sigaa_times)
+    }
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind, span: Range<usize>) -> ParseError {
+        ParseError { kind, span }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let descricao = match &self.kind {
+            ParseErrorKind::Empty => "nenhum horário informado".to_string(),
+            ParseErrorKind::MissingDia => "esperava um dia (1 a 7)".to_string(),
+            ParseErrorKind::InvalidDia(c) => format!("'{}' não é um dia válido (1 a 7)", c),
+            ParseErrorKind::DuplicatedDia(dia) => format!("dia {} repetido", dia),
+            ParseErrorKind::MissingTurno => "esperava um turno (M, T ou N)".to_string(),
+            ParseErrorKind::InvalidTurno(c) => format!("'{}' não é um turno válido (M, T ou N)", c),
+            ParseErrorKind::MissingPeriodo => "esperava os números dos períodos".to_string(),
+            ParseErrorKind::InvalidPeriodo(c) => format!("'{}' não é um período válido para o turno", c),
+            ParseErrorKind::DuplicatedPeriodo(periodo) => format!("período {} repetido", periodo),
+            ParseErrorKind::UnexpectedChar(c) => format!("caractere inesperado '{}'", c),
+            ParseErrorKind::PeriodosNotPaired => "os períodos não formam pares (ex: M12, T34, N12)".to_string(),
+        };
+
+        write!(f, "{} (posição {}..{})", descricao, self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Lê todos os códigos guardando o trecho da entrada de onde cada um veio.
+fn parse_spanned(input: &str) -> Result<Vec<(SigaaCode, Range<usize>)>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut pos = 0;
+    let mut codes = Vec::new();
+
+    loop {
+        while chars.get(pos).is_some_and(|c| c.is_whitespace()) {
+            pos += 1;
+        }
+
+        if pos >= chars.len() {
+            break;
+        }
+
+        let start = pos;
+        let code = parse_code(&chars, &mut pos)?;
+        codes.push((code, start..pos));
+    }
+
+    match codes.is_empty() {
+        true => Err(ParseError::new(ParseErrorKind::Empty, 0..chars.len())),
+        false => Ok(codes),
+    }
+}
+
+fn parse_code(chars: &[char], pos: &mut usize) -> Result<SigaaCode, ParseError> {
+    let dias = parse_dias(chars, pos)?;
+    let mut periodos = BTreeSet::new();
+
+    loop {
+        parse_grupo(chars, pos, &mut periodos)?;
+
+        match chars.get(*pos) {
+            None => break,
+            Some(c) if c.is_whitespace() => break,
+            Some('M' | 'T' | 'N') => continue,
+            Some(&c) => return Err(ParseError::new(ParseErrorKind::UnexpectedChar(c), *pos..*pos + 1)),
+        }
+    }
+
+    Ok(SigaaCode::new(dias, periodos))
+}
+
+fn parse_dias(chars: &[char], pos: &mut usize) -> Result<BTreeSet<Dia>, ParseError> {
+    let mut dias = BTreeSet::new();
+
+    while let Some(&c) = chars.get(*pos).filter(|c| c.is_ascii_digit()) {
+        let dia = Dia::try_from(c.to_string().as_str()).map_err(|_| ParseError::new(ParseErrorKind::InvalidDia(c), *pos..*pos + 1))?;
+
+        if !dias.insert(dia) {
+            return Err(ParseError::new(ParseErrorKind::DuplicatedDia(dia), *pos..*pos + 1));
+        }
+
+        *pos += 1;
+    }
+
+    match dias.is_empty() {
+        true => Err(ParseError::new(ParseErrorKind::MissingDia, marca(chars, *pos))),
+        false => Ok(dias),
+    }
+}
+
+fn parse_grupo(chars: &[char], pos: &mut usize, periodos: &mut BTreeSet<Periodo>) -> Result<(), ParseError> {
+    let letra = match chars.get(*pos) {
+        Some(&c @ ('M' | 'T' | 'N')) => c,
+        Some(c) if c.is_whitespace() => return Err(ParseError::new(ParseErrorKind::MissingTurno, marca(chars, *pos))),
+        Some(&c) => return Err(ParseError::new(ParseErrorKind::InvalidTurno(c), *pos..*pos + 1)),
+        None => return Err(ParseError::new(ParseErrorKind::MissingTurno, marca(chars, *pos))),
+    };
+    *pos += 1;
+
+    let inicio = *pos;
+    while let Some(&c) = chars.get(*pos).filter(|c| c.is_ascii_digit()) {
+        let periodo = c
+            .to_digit(10)
+            .and_then(|numero| Periodo::new(letra, numero as u8).ok())
+            .ok_or(ParseError::new(ParseErrorKind::InvalidPeriodo(c), *pos..*pos + 1))?;
+
+        if !periodos.insert(periodo) {
+            return Err(ParseError::new(ParseErrorKind::DuplicatedPeriodo(periodo), *pos..*pos + 1));
+        }
+
+        *pos += 1;
+    }
+
+    match *pos == inicio {
+        true => Err(ParseError::new(ParseErrorKind::MissingPeriodo, marca(chars, *pos))),
+        false => Ok(()),
+    }
+}
+
+/// Trecho de um caractere na posição, ou vazio se a entrada já terminou.
+fn marca(chars: &[char], pos: usize) -> Range<usize> {
+    pos..(pos + 1).min(chars.len())
+}
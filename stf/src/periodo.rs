@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use super::{Periodo, SigaaTimeErrors, Turno};
+
+impl Periodo {
+    /// Todos os períodos, na ordem em que acontecem no dia.
+    pub const TODOS: [Periodo; 16] = [Periodo::M1, Periodo::M2, Periodo::M3, Periodo::M4, Periodo::M5, Periodo::M6, Periodo::T1, Periodo::T2, Periodo::T3, Periodo::T4, Periodo::T5, Periodo::T6, Periodo::N1, Periodo::N2, Periodo::N3, Periodo::N4];
+
+    /// Cria um período a partir da letra do turno (`M`, `T` ou `N`) e do seu número.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::Periodo;
+    /// assert_eq!(Periodo::new('T', 4), Ok(Periodo::T4));
+    /// assert!(Periodo::new('N', 5).is_err());
+    /// ```
+    pub fn new(letra: char, numero: u8) -> Result<Periodo, SigaaTimeErrors> {
+        let base = match letra {
+            'M' => 0,
+            'T' => 6,
+            'N' => 12,
+            _ => return Err(SigaaTimeErrors::InvalidStringToPeriodo),
+        };
+        let limite = if letra == 'N' { 4 } else { 6 };
+
+        match (1..=limite).contains(&numero) {
+            true => Periodo::try_from(base + numero as usize - 1),
+            false => Err(SigaaTimeErrors::InvalidStringToPeriodo),
+        }
+    }
+
+    /// Letra do turno a que o período pertence: `M`, `T` ou `N`.
+    pub fn letra(&self) -> char {
+        match usize::from(*self) {
+            0..=5 => 'M',
+            6..=11 => 'T',
+            _ => 'N',
+        }
+    }
+
+    /// Número do período dentro do seu turno (1 a 6, ou 1 a 4 à noite).
+    pub fn numero(&self) -> u8 {
+        let index = usize::from(*self);
+        (index % 6) as u8 + 1
+    }
+
+    /// Retorna o `Turno` (par de períodos) que contém este período.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{HorarioDiurno, Periodo, Turno};
+    /// assert_eq!(Periodo::M4.turno(), Turno::Manhã(HorarioDiurno::Segundo));
+    /// ```
+    pub fn turno(&self) -> Turno {
+        let index: usize = (*self).into();
+        Turno::try_from(index / 2).expect("todo período pertence a um turno")
+    }
+}
+
+impl Turno {
+    /// Retorna os dois períodos que formam este turno, ex: `T34` -> `[T3, T4]`.
+    pub fn periodos(&self) -> [Periodo; 2] {
+        let index: usize = (*self).into();
+        [Periodo::TODOS[index * 2], Periodo::TODOS[index * 2 + 1]]
+    }
+}
+
+impl TryFrom<[Periodo; 2]> for Turno {
+    type Error = SigaaTimeErrors;
+
+    fn try_from([primeiro, segundo]: [Periodo; 2]) -> Result<Self, Self::Error> {
+        let turno = primeiro.turno();
+
+        match turno.periodos() == [primeiro, segundo] {
+            true => Ok(turno),
+            false => Err(SigaaTimeErrors::PeriodosNotPaired),
+        }
+    }
+}
+
+impl Display for Periodo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.letra(), self.numero())
+    }
+}
+
+impl TryFrom<&str> for Periodo {
+    type Error = SigaaTimeErrors;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut chars = value.chars();
+
+        match (chars.next(), chars.next().and_then(|c| c.to_digit(10)), chars.next()) {
+            (Some(letra), Some(numero), None) => Periodo::new(letra, numero as u8),
+            _ => Err(SigaaTimeErrors::InvalidStringToPeriodo),
+        }
+    }
+}
+
+impl TryFrom<usize> for Periodo {
+    type Error = SigaaTimeErrors;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        Periodo::TODOS
+            .get(value)
+            .copied()
+            .ok_or(SigaaTimeErrors::InvalidUsizeToPeriodo)
+    }
+}
+
+impl From<Periodo> for usize {
+    fn from(value: Periodo) -> Self {
+        value as usize
+    }
+}
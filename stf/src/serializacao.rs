@@ -0,0 +1,112 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Dia, HorarioDiurno, HorarioNoturno, SigaaTime, SigaaTimeSet, Turno};
+
+/// Formatos aceitos na leitura de um `SigaaTime`: o código atual ou o objeto das versões antigas.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SigaaTimeSalvo {
+    Codigo(String),
+    Estruturado { dia: Dia, turno: Turno },
+}
+
+/// Formatos aceitos na leitura de um `SigaaTimeSet`: os códigos ou uma lista de horários.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SigaaTimeSetSalvo {
+    Codigos(String),
+    Lista(Vec<SigaaTime>),
+}
+
+/// Formatos aceitos na leitura de um `Turno`: o código atual ou o enum das versões antigas.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TurnoSalvo {
+    Codigo(String),
+    Estruturado(TurnoEstruturado),
+}
+
+/// `Turno` como era serializado antes, ex: `{"Tarde": "Segundo"}`.
+#[derive(Deserialize)]
+enum TurnoEstruturado {
+    Manhã(HorarioDiurno),
+    Tarde(HorarioDiurno),
+    Noite(HorarioNoturno),
+}
+
+impl Serialize for Dia {
+    /// Código do dia, ex: `"3"` para terça.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Dia {
+    /// Aceita o código (`"3"`) ou o nome usado pelas versões antigas (`"Terça"`).
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let valor = String::deserialize(deserializer)?;
+
+        Dia::try_from(valor.as_str())
+            .ok()
+            .or_else(|| {
+                Dia::TODOS
+                    .into_iter()
+                    .find(|dia| format!("{dia:?}") == valor)
+            })
+            .ok_or_else(|| D::Error::custom(format!("'{valor}' não é um dia válido (1 a 7)")))
+    }
+}
+
+impl Serialize for Turno {
+    /// Código do turno, ex: `"T34"`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Turno {
+    /// Aceita o código (`"T34"`) ou o formato das versões antigas (`{"Tarde": "Segundo"}`).
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match TurnoSalvo::deserialize(deserializer)? {
+            TurnoSalvo::Codigo(codigo) => Turno::try_from(codigo.as_str()).map_err(|_| D::Error::custom(format!("'{codigo}' não é um turno válido (ex: M12, T34, N12)"))),
+            TurnoSalvo::Estruturado(TurnoEstruturado::Manhã(horario)) => Ok(Turno::Manhã(horario)),
+            TurnoSalvo::Estruturado(TurnoEstruturado::Tarde(horario)) => Ok(Turno::Tarde(horario)),
+            TurnoSalvo::Estruturado(TurnoEstruturado::Noite(horario)) => Ok(Turno::Noite(horario)),
+        }
+    }
+}
+
+impl Serialize for SigaaTime {
+    /// Código do horário, ex: `"3T34"`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SigaaTime {
+    /// Aceita o código (`"3T34"`) ou o objeto das versões antigas (`{"dia": "Terça", "turno": {"Tarde": "Segundo"}}`).
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match SigaaTimeSalvo::deserialize(deserializer)? {
+            SigaaTimeSalvo::Codigo(codigo) => SigaaTime::try_from(codigo.as_str()).map_err(|_| D::Error::custom(format!("'{codigo}' não é um horário válido (ex: 3T34)"))),
+            SigaaTimeSalvo::Estruturado { dia, turno } => Ok(SigaaTime::new(dia, turno)),
+        }
+    }
+}
+
+impl Serialize for SigaaTimeSet {
+    /// Códigos do SIGAA, ex: `"24M12 6T34"`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SigaaTimeSet {
+    /// Aceita os códigos (`"24M12 6T34"`, ou `""` para o conjunto vazio) ou uma lista de horários, como a de `BTreeSet<SigaaTime>`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match SigaaTimeSetSalvo::deserialize(deserializer)? {
+            SigaaTimeSetSalvo::Codigos(codigos) => SigaaTimeSet::try_from(codigos.as_str()).map_err(|erro| D::Error::custom(format!("'{codigos}' não é um horário válido: {erro}"))),
+            SigaaTimeSetSalvo::Lista(horarios) => Ok(horarios.into_iter().collect()),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use super::{Dia, Periodo, SigaaSlot, SigaaTime, SigaaTimeErrors};
+
+impl SigaaSlot {
+    /// Cria uma nova instância de `SigaaSlot`.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{Dia, Periodo, SigaaSlot};
+    /// let slot = SigaaSlot::new(Dia::Quarta, Periodo::T4);
+    /// assert_eq!(slot.to_string(), "4T4");
+    /// ```
+    pub fn new(dia: Dia, periodo: Periodo) -> SigaaSlot {
+        SigaaSlot { dia, periodo }
+    }
+
+    /// Retorna o `SigaaTime` (horário em par) que contém este período.
+    pub fn sigaa_time(&self) -> SigaaTime {
+        SigaaTime::new(self.dia, self.periodo.turno())
+    }
+}
+
+impl SigaaTime {
+    /// Divide o horário nos dois períodos que o compõem, ex: `2M12` -> `[2M1, 2M2]`.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{SigaaSlot, SigaaTime};
+    /// let sigaa_time = SigaaTime::new_from_strings("3", "T34").unwrap();
+    /// let [primeiro, segundo] = sigaa_time.slots();
+    /// assert_eq!(primeiro.to_string(), "3T3");
+    /// assert_eq!(segundo.to_string(), "3T4");
+    /// ```
+    pub fn slots(&self) -> [SigaaSlot; 2] {
+        self.turno
+            .periodos()
+            .map(|periodo| SigaaSlot::new(self.dia, periodo))
+    }
+
+    /// Reagrupa um conjunto de períodos em horários pareados.
+    ///
+    /// A conversão é o inverso exato de `SigaaTime::slots`: só é possível se cada período vier
+    /// acompanhado do seu par. Caso contrário, retorna `SigaaTimeErrors::PeriodosNotPaired`.
+    pub fn from_slots(slots: &BTreeSet<SigaaSlot>) -> Result<BTreeSet<SigaaTime>, SigaaTimeErrors> {
+        slots
+            .iter()
+            .map(|slot| {
+                let sigaa_time = slot.sigaa_time();
+                match sigaa_time
+                    .slots()
+                    .iter()
+                    .all(|par| slots.contains(par))
+                {
+                    true => Ok(sigaa_time),
+                    false => Err(SigaaTimeErrors::PeriodosNotPaired),
+                }
+            })
+            .collect()
+    }
+}
+
+impl From<SigaaTime> for [SigaaSlot; 2] {
+    fn from(value: SigaaTime) -> Self {
+        value.slots()
+    }
+}
+
+impl TryFrom<[SigaaSlot; 2]> for SigaaTime {
+    type Error = SigaaTimeErrors;
+
+    fn try_from([primeiro, segundo]: [SigaaSlot; 2]) -> Result<Self, Self::Error> {
+        if primeiro.dia != segundo.dia {
+            return Err(SigaaTimeErrors::PeriodosNotPaired);
+        }
+
+        let turno = [primeiro.periodo, segundo.periodo].try_into()?;
+
+        Ok(SigaaTime::new(primeiro.dia, turno))
+    }
+}
+
+impl TryFrom<&str> for SigaaSlot {
+    type Error = SigaaTimeErrors;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.char_indices().nth(1) {
+            Some((meio, _)) => {
+                let dia: Dia = value[..meio].try_into()?;
+                let periodo: Periodo = value[meio..].try_into()?;
+                Ok(SigaaSlot::new(dia, periodo))
+            }
+            None => Err(SigaaTimeErrors::InvalidStringToSigaaSlot),
+        }
+    }
+}
+
+impl Display for SigaaSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.dia, self.periodo)
+    }
+}
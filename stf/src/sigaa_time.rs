@@ -1,7 +1,5 @@
 use std::fmt::Display;
 
-use regex::Regex;
-
 use super::{Dia, SigaaTime, SigaaTimeErrors, Turno};
 
 impl SigaaTime {
@@ -61,17 +59,13 @@ impl TryFrom<&str> for SigaaTime {
     type Error = SigaaTimeErrors;
 
     fn try_from(value: &str) -> Result<SigaaTime, SigaaTimeErrors> {
-        let regex = Regex::new(r"^(\d{1,5})([MTN])(\d{2,6})$").unwrap();
+        let mut sigaa_times = SigaaTime::parse_codes(value)
+            .map_err(|_| SigaaTimeErrors::InvalidStringToSigaaTime)?
+            .into_iter();
 
-        match regex.captures(value) {
-            Some(capturas) => {
-                let dias = capturas[1].to_string();
-                let turnos = capturas[2].to_string();
-                let horarios = capturas[3].to_string();
-                let turno_horarios = turnos + &horarios;
-                SigaaTime::new_from_strings(&dias, &turno_horarios)
-            }
-            None => Err(SigaaTimeErrors::InvalidStringToSigaaTime),
+        match (sigaa_times.next(), sigaa_times.next()) {
+            (Some(sigaa_time), None) => Ok(sigaa_time),
+            _ => Err(SigaaTimeErrors::InvalidStringToSigaaTime),
         }
     }
 }
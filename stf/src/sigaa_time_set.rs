@@ -0,0 +1,313 @@
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, Not, Sub, SubAssign};
+
+use super::{Dia, ParseError, SigaaTime, SigaaTimeSet, SigaaTimeSetIter, Turno};
+
+/// Bits de todos os horários da semana: oito turnos para cada um dos sete dias.
+const TODOS: u64 = (1 << 56) - 1;
+
+impl SigaaTimeSet {
+    /// Conjunto vazio.
+    pub const fn new() -> SigaaTimeSet {
+        SigaaTimeSet { bits: 0 }
+    }
+
+    /// Todos os horários da semana, de domingo a sábado.
+    pub const fn all() -> SigaaTimeSet {
+        SigaaTimeSet { bits: TODOS }
+    }
+
+    /// Adiciona o horário, retornando `false` se ele já estava no conjunto.
+    pub fn insert(&mut self, sigaa_time: SigaaTime) -> bool {
+        let novo = !self.contains(&sigaa_time);
+        self.bits |= bit(sigaa_time);
+        novo
+    }
+
+    /// Retira o horário, retornando `false` se ele não estava no conjunto.
+    pub fn remove(&mut self, sigaa_time: &SigaaTime) -> bool {
+        let presente = self.contains(sigaa_time);
+        self.bits &= !bit(*sigaa_time);
+        presente
+    }
+
+    /// Verifica se o horário está no conjunto.
+    pub fn contains(&self, sigaa_time: &SigaaTime) -> bool {
+        self.bits & bit(*sigaa_time) != 0
+    }
+
+    /// Quantidade de horários no conjunto.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Verifica se o conjunto não tem nenhum horário.
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Retira todos os horários.
+    pub fn clear(&mut self) {
+        self.bits = 0
+    }
+
+    /// Horários que estão em qualquer um dos conjuntos.
+    pub fn union(&self, outro: &SigaaTimeSet) -> SigaaTimeSet {
+        SigaaTimeSet { bits: self.bits | outro.bits }
+    }
+
+    /// Horários que estão nos dois conjuntos.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::SigaaTimeSet;
+    ///
+    /// let calculo = SigaaTimeSet::try_from("246M12").unwrap();
+    /// let fisica = SigaaTimeSet::try_from("4M12 4T34").unwrap();
+    ///
+    /// assert_eq!(calculo.intersection(&fisica).to_string(), "4M12");
+    /// assert_eq!((calculo | fisica).to_string(), "246M12 4T34");
+    /// assert!(!calculo.is_disjoint(&fisica));
+    /// ```
+    pub fn intersection(&self, outro: &SigaaTimeSet) -> SigaaTimeSet {
+        SigaaTimeSet { bits: self.bits & outro.bits }
+    }
+
+    /// Horários deste conjunto que não estão no outro.
+    pub fn difference(&self, outro: &SigaaTimeSet) -> SigaaTimeSet {
+        SigaaTimeSet { bits: self.bits & !outro.bits }
+    }
+
+    /// Horários que estão em apenas um dos conjuntos.
+    pub fn symmetric_difference(&self, outro: &SigaaTimeSet) -> SigaaTimeSet {
+        SigaaTimeSet { bits: self.bits ^ outro.bits }
+    }
+
+    /// Horários da semana que não estão no conjunto.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::SigaaTimeSet;
+    ///
+    /// let livres = SigaaTimeSet::try_from("2M12").unwrap().complement();
+    /// assert_eq!(livres.len(), 55);
+    /// assert_eq!(livres.complement().to_string(), "2M12");
+    /// ```
+    pub fn complement(&self) -> SigaaTimeSet {
+        SigaaTimeSet { bits: !self.bits & TODOS }
+    }
+
+    /// Verifica se os conjuntos não têm nenhum horário em comum.
+    pub fn is_disjoint(&self, outro: &SigaaTimeSet) -> bool {
+        self.bits & outro.bits == 0
+    }
+
+    /// Verifica se todos os horários deste conjunto estão no outro.
+    pub fn is_subset(&self, outro: &SigaaTimeSet) -> bool {
+        self.bits & !outro.bits == 0
+    }
+
+    /// Verifica se todos os horários do outro conjunto estão neste.
+    pub fn is_superset(&self, outro: &SigaaTimeSet) -> bool {
+        outro.is_subset(self)
+    }
+
+    /// Horários do conjunto, na ordem de `SigaaTime`.
+    ///
+    /// # Exemplo
+    ///
+    /// ```
+    /// use stf::{SigaaTime, SigaaTimeSet};
+    ///
+    /// let conjunto = SigaaTimeSet::try_from("4M12 2N34 2M12").unwrap();
+    /// let horarios: Vec<String> = conjunto.iter().map(|horario| horario.to_string()).collect();
+    ///
+    /// assert_eq!(horarios, ["2M12", "2N34", "4M12"]);
+    /// assert_eq!(conjunto.iter().next_back(), Some(SigaaTime::try_from("4M12").unwrap()));
+    /// ```
+    pub fn iter(&self) -> SigaaTimeSetIter {
+        SigaaTimeSetIter { bits: self.bits }
+    }
+}
+
+impl Iterator for SigaaTimeSetIter {
+    type Item = SigaaTime;
+
+    fn next(&mut self) -> Option<SigaaTime> {
+        match self.bits {
+            0 => None,
+            bits => {
+                self.bits &= bits - 1;
+                Some(horario(bits.trailing_zeros() as usize))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let restantes = self.bits.count_ones() as usize;
+        (restantes, Some(restantes))
+    }
+}
+
+impl DoubleEndedIterator for SigaaTimeSetIter {
+    fn next_back(&mut self) -> Option<SigaaTime> {
+        match self.bits {
+            0 => None,
+            bits => {
+                let indice = 63 - bits.leading_zeros() as usize;
+                self.bits &= !(1 << indice);
+                Some(horario(indice))
+            }
+        }
+    }
+}
+
+impl ExactSizeIterator for SigaaTimeSetIter {}
+
+impl IntoIterator for SigaaTimeSet {
+    type Item = SigaaTime;
+    type IntoIter = SigaaTimeSetIter;
+
+    fn into_iter(self) -> SigaaTimeSetIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &SigaaTimeSet {
+    type Item = SigaaTime;
+    type IntoIter = SigaaTimeSetIter;
+
+    fn into_iter(self) -> SigaaTimeSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<SigaaTime> for SigaaTimeSet {
+    fn from_iter<I: IntoIterator<Item = SigaaTime>>(iter: I) -> Self {
+        let mut conjunto = SigaaTimeSet::new();
+        conjunto.extend(iter);
+        conjunto
+    }
+}
+
+impl<'a> FromIterator<&'a SigaaTime> for SigaaTimeSet {
+    fn from_iter<I: IntoIterator<Item = &'a SigaaTime>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl Extend<SigaaTime> for SigaaTimeSet {
+    fn extend<I: IntoIterator<Item = SigaaTime>>(&mut self, iter: I) {
+        for sigaa_time in iter {
+            self.insert(sigaa_time);
+        }
+    }
+}
+
+impl From<&BTreeSet<SigaaTime>> for SigaaTimeSet {
+    fn from(horarios: &BTreeSet<SigaaTime>) -> Self {
+        horarios.iter().collect()
+    }
+}
+
+impl From<SigaaTimeSet> for BTreeSet<SigaaTime> {
+    fn from(conjunto: SigaaTimeSet) -> Self {
+        conjunto.iter().collect()
+    }
+}
+
+impl TryFrom<&str> for SigaaTimeSet {
+    type Error = ParseError;
+
+    /// Lê códigos do SIGAA separados por espaço, como `SigaaTime::parse_codes`. Uma string vazia (ou só com
+    /// espaços) é o conjunto vazio, que é como ele é escrito.
+    fn try_from(value: &str) -> Result<Self, ParseError> {
+        match value.trim().is_empty() {
+            true => Ok(SigaaTimeSet::new()),
+            false => Ok(SigaaTimeSet::from(&SigaaTime::parse_codes(value)?)),
+        }
+    }
+}
+
+impl Display for SigaaTimeSet {
+    /// Menor lista de códigos do SIGAA com os horários do conjunto (veja `SigaaCode::encode`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SigaaTime::format_codes(&BTreeSet::from(*self)))
+    }
+}
+
+impl Debug for SigaaTimeSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl BitOr for SigaaTimeSet {
+    type Output = SigaaTimeSet;
+
+    fn bitor(self, outro: SigaaTimeSet) -> SigaaTimeSet {
+        self.union(&outro)
+    }
+}
+
+impl BitAnd for SigaaTimeSet {
+    type Output = SigaaTimeSet;
+
+    fn bitand(self, outro: SigaaTimeSet) -> SigaaTimeSet {
+        self.intersection(&outro)
+    }
+}
+
+impl Sub for SigaaTimeSet {
+    type Output = SigaaTimeSet;
+
+    fn sub(self, outro: SigaaTimeSet) -> SigaaTimeSet {
+        self.difference(&outro)
+    }
+}
+
+impl BitXor for SigaaTimeSet {
+    type Output = SigaaTimeSet;
+
+    fn bitxor(self, outro: SigaaTimeSet) -> SigaaTimeSet {
+        self.symmetric_difference(&outro)
+    }
+}
+
+impl Not for SigaaTimeSet {
+    type Output = SigaaTimeSet;
+
+    fn not(self) -> SigaaTimeSet {
+        self.complement()
+    }
+}
+
+impl BitOrAssign for SigaaTimeSet {
+    fn bitor_assign(&mut self, outro: SigaaTimeSet) {
+        *self = *self | outro
+    }
+}
+
+impl BitAndAssign for SigaaTimeSet {
+    fn bitand_assign(&mut self, outro: SigaaTimeSet) {
+        *self = *self & outro
+    }
+}
+
+impl SubAssign for SigaaTimeSet {
+    fn sub_assign(&mut self, outro: SigaaTimeSet) {
+        *self = *self - outro
+    }
+}
+
+/// Bit do horário: os turnos de cada dia ficam juntos, então a ordem dos bits é a ordem de `SigaaTime`.
+fn bit(sigaa_time: SigaaTime) -> u64 {
+    1 << (usize::from(sigaa_time.dia) * 8 + usize::from(sigaa_time.turno))
+}
+
+fn horario(indice: usize) -> SigaaTime {
+    SigaaTime::new(Dia::TODOS[indice / 8], Turno::TODOS[indice % 8])
+}
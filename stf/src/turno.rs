@@ -2,6 +2,11 @@ use std::fmt::Display;
 
 use super::{HorarioDiurno, HorarioNoturno, SigaaTimeErrors, Turno};
 
+impl Turno {
+    /// Todos os turnos, na ordem em que acontecem no dia.
+    pub const TODOS: [Turno; 8] = [Turno::Manhã(HorarioDiurno::Primeiro), Turno::Manhã(HorarioDiurno::Segundo), Turno::Manhã(HorarioDiurno::Terceiro), Turno::Tarde(HorarioDiurno::Primeiro), Turno::Tarde(HorarioDiurno::Segundo), Turno::Tarde(HorarioDiurno::Terceiro), Turno::Noite(HorarioNoturno::Primeiro), Turno::Noite(HorarioNoturno::Segundo)];
+}
+
 impl Display for Turno {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1,9 +1,13 @@
 use class::Disciplina;
+use schedule::DiasLetivos;
+use stf::TabelaHorarios;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct DisciplineButtonProps {
     pub disciplina: Disciplina,
+    pub tabela: TabelaHorarios,
+    pub dias: DiasLetivos,
     pub onremove: Callback<Disciplina>,
 }
 
@@ -12,13 +16,23 @@ pub fn discipline_button(props: &DisciplineButtonProps) -> Html {
     let disciplina = props.disciplina.clone();
     let on_remove = props.onremove.clone();
 
-    let hours = disciplina.sigaa_time.len() * 30;
+    let hours = (props
+        .dias
+        .minutos(&props.tabela, &disciplina.slots()) as f64
+        / 60.0)
+        .round();
 
-    let text = format!("{} ({}) - {}H", disciplina.nome, disciplina.abreviacao, hours);
+    let turma = match (&disciplina.codigo, &disciplina.turma) {
+        (Some(codigo), Some(turma)) => format!("{} T{} - ", codigo, turma),
+        (Some(codigo), None) => format!("{} - ", codigo),
+        _ => String::new(),
+    };
+
+    let text = format!("{}{} ({}) - {}H", turma, disciplina.nome, disciplina.abreviacao, hours);
 
     let on_click_remove = Callback::from(move |_| on_remove.emit(disciplina.clone()));
     html! {
-    <button class="w-full p-2 rounded cursor-pointer transition duration-300 ease-in-out mb-2 hover:bg-blue-700 border " onclick={on_click_remove}>
+    <button class="w-full p-2 rounded cursor-pointer transition duration-300 ease-in-out mb-2 hover:bg-blue-700 border " title={props.disciplina.to_string()} onclick={on_click_remove}>
         { text }
     </button>
     }
@@ -0,0 +1,22 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct HistoryButtonsProps {
+    pub can_undo: bool,
+    pub can_redo: bool,
+    pub on_undo: Callback<()>,
+    pub on_redo: Callback<()>,
+}
+
+#[function_component(HistoryButtons)]
+pub fn history_buttons(props: &HistoryButtonsProps) -> Html {
+    let on_undo = props.on_undo.reform(|_: MouseEvent| ());
+    let on_redo = props.on_redo.reform(|_: MouseEvent| ());
+
+    html! {
+    <div class="flex flex-row space-x-2 mr-5">
+        <button class="flex-1 p-2 rounded border cursor-pointer transition duration-300 ease-in-out hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed" disabled={!props.can_undo} onclick={on_undo}>{"Desfazer"}</button>
+        <button class="flex-1 p-2 rounded border cursor-pointer transition duration-300 ease-in-out hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed" disabled={!props.can_redo} onclick={on_redo}>{"Refazer"}</button>
+    </div>
+    }
+}
@@ -1,5 +1,6 @@
 pub mod discipline;
 pub mod github_link;
+pub mod history;
 pub mod schedule_unity;
 pub mod text_input;
 pub mod total_hour;
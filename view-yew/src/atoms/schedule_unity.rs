@@ -12,10 +12,19 @@ pub fn UnityComponent(props: &ScheduleUnity) -> Html {
         None => default_classes.to_string(),
     };
 
+    let detalhes = props
+        .disciplinas()
+        .map(|disciplina| match disciplina.local_em(&props.horario) {
+            Some(local) => format!("{}\n{} - {}", disciplina, disciplina.atividade_em(&props.horario), local),
+            None => format!("{}\n{}", disciplina, disciplina.atividade_em(&props.horario)),
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
     html! {
-        <div class={classes}>
+        <div class={classes} title={detalhes}>
         {match &props.disciplina {
-            Some(disciplina) => disciplina.abreviacao.clone(),
+            Some(_) => props.disciplinas().map(|disciplina| disciplina.abreviacao.clone()).collect::<Vec<String>>().join(" / "),
             None => props.horario.to_string()
         }}
         </div>
@@ -1,4 +1,5 @@
 use super::super::atoms::github_link::GithubLink;
+use super::super::atoms::history::HistoryButtons;
 use super::super::atoms::total_hour::TotalHourComponent;
 use super::discipline_list::DisciplineListComponent;
 use super::form::*;
@@ -6,60 +7,88 @@ use super::schedule::ScheduleComponent;
 use class::Disciplina;
 use gloo::console::log;
 use gloo_storage::{LocalStorage, Storage};
-use schedule::Schedule;
+use schedule::{DiasLetivos, Documento, Edicao, Historico, Schedule, ScheduleError};
 use std::ops::Deref;
+use stf::TabelaHorarios;
 use yew::*;
 
 #[function_component]
 pub fn App() -> Html {
     let schedule = use_state(|| Schedule::new());
+    let historico = use_state(|| Historico::new());
+    let tabela = use_state(TabelaHorarios::default);
+    let dias = use_state(DiasLetivos::default);
 
     let total_hours = use_state(|| 0);
 
-    let schedule_clone = schedule.clone();
     {
+        let schedule = schedule.clone();
+        let historico = historico.clone();
+        let tabela = tabela.clone();
+        let dias = dias.clone();
         use_effect_with((), move |_| {
-            let disciplinas: Vec<Disciplina> = get_saved_disciplines();
-            log!("Loading saved disciplines...");
-
-            let mut new_schedule = schedule_clone.deref().clone();
-            for disciplina in disciplinas {
-                log!(&format!("{:?}", &disciplina));
-                new_schedule.insert(disciplina).unwrap();
-            }
+            log!("Loading saved schedule...");
+            let (documento, saved_schedule) = load_document();
 
-            schedule_clone.set(new_schedule);
+            schedule.set(saved_schedule);
+            historico.set(documento.historico);
+            tabela.set(documento.configuracoes.tabela);
+            dias.set(match &documento.semestre {
+                Some(semestre) => DiasLetivos::from(semestre),
+                None => DiasLetivos::default(),
+            });
         });
     }
+
     let on_form_submit = {
         let schedule = schedule.clone();
+        let historico = historico.clone();
         Callback::from(move |form_data: Data| {
-            let updated_schedule = updated_schedule_with_form_data(schedule.deref().clone(), &form_data);
-            schedule.set(updated_schedule);
+            log!(format!("FormData values: \n    Nome da disciplina: {}\n    Horário da disciplina: {}", form_data.nome.clone(), form_data.horario.clone()));
+
+            match Disciplina::new_stringify(&form_data.nome, &form_data.horario) {
+                Ok(disciplina) => edit_schedule(&schedule, &historico, |sched, hist| {
+                    hist.execute(sched, Edicao::Insert(disciplina))
+                        .map(|_| ())
+                }),
+                Err(error) => gloo::dialogs::alert(&format!("{:?}", error)),
+            }
         })
     };
 
     let remove_discipline_from_schedule = {
         let schedule = schedule.clone();
+        let historico = historico.clone();
         Callback::from(move |disciplina: Disciplina| {
-            let mut updated_schedule = schedule.deref().clone();
-            updated_schedule
-                .remove(disciplina.clone())
-                .unwrap();
-            schedule.set(updated_schedule);
-
-            let mut disciplinas = get_saved_disciplines();
-            disciplinas.retain(|d| d != &disciplina);
-            LocalStorage::set("disciplinas", &disciplinas).expect("Failed to update LocalStorage");
+            edit_schedule(&schedule, &historico, |sched, hist| {
+                hist.execute(sched, Edicao::Remove(disciplina))
+                    .map(|_| ())
+            });
         })
     };
-    let discipline_list = get_saved_disciplines();
 
-    {
+    let on_undo = {
+        let schedule = schedule.clone();
+        let historico = historico.clone();
+        Callback::from(move |_| edit_schedule(&schedule, &historico, |sched, hist| hist.undo(sched).map(|_| ())))
+    };
+
+    let on_redo = {
         let schedule = schedule.clone();
+        let historico = historico.clone();
+        Callback::from(move |_| edit_schedule(&schedule, &historico, |sched, hist| hist.redo(sched).map(|_| ())))
+    };
+
+    let discipline_list: Vec<Disciplina> = schedule
+        .disciplines()
+        .into_iter()
+        .cloned()
+        .collect();
+
+    {
         let total_hours = total_hours.clone();
-        use_effect_with(schedule.clone(), move |_| {
-            let new_total_hours = calculate_total_hours();
+        use_effect_with((schedule.clone(), tabela.clone(), dias.clone()), move |(schedule, tabela, dias)| {
+            let new_total_hours = calculate_total_hours(schedule, tabela, dias);
             total_hours.set(new_total_hours);
             || ()
         });
@@ -68,54 +97,94 @@ pub fn App() -> Html {
     <div class="flex flex-row justify-around items-start bg-white p-5 rounded-lg shadow-md w-4/5 max-w-[1200px]">
         <div class="relative flex flex-col space-y-4" >
             <FormComponent on_submit={on_form_submit}/>
+            <HistoryButtons can_undo={historico.can_undo()} can_redo={historico.can_redo()} on_undo={on_undo} on_redo={on_redo}/>
             <TotalHourComponent total_hours={*total_hours}/>
             <GithubLink />
             </div>
         <div class="w-full">
             <ScheduleComponent schedule={(*schedule).clone()} />
-            <DisciplineListComponent discipline_list={discipline_list} on_remove={remove_discipline_from_schedule}/>
+            <DisciplineListComponent discipline_list={discipline_list} tabela={(*tabela).clone()} dias={*dias} on_remove={remove_discipline_from_schedule}/>
         </div>
     </div>
     }
 }
 
-fn updated_schedule_with_form_data(mut sched: Schedule, form_data: &Data) -> Schedule {
-    log!(format!("FormData values: \n    Nome da disciplina: {}\n    Horário da disciplina: {}", form_data.nome.clone(), form_data.horario.clone()));
-
-    let new_disciplina = Disciplina::new_stringify(&form_data.nome, &form_data.horario);
+/// Aplica uma edição em cópias do cronograma e do histórico; se der certo, atualiza o estado e salva.
+fn edit_schedule(schedule: &UseStateHandle<Schedule>, historico: &UseStateHandle<Historico>, edit: impl FnOnce(&mut Schedule, &mut Historico) -> Result<(), ScheduleError>) {
+    let mut updated_schedule = schedule.deref().clone();
+    let mut updated_historico = historico.deref().clone();
 
-    match new_disciplina {
-        Ok(disciplina) => match sched.insert(disciplina.clone()) {
-            Ok(_) => add_disciplina_to_local_storage(disciplina),
-            Err(error) => gloo::dialogs::alert(&format!("{:?}", error)),
-        },
+    match edit(&mut updated_schedule, &mut updated_historico) {
+        Ok(()) => {
+            save_schedule(&updated_schedule, &updated_historico);
+            schedule.set(updated_schedule);
+            historico.set(updated_historico);
+        }
         Err(error) => gloo::dialogs::alert(&format!("{:?}", error)),
     }
-
-    sched
 }
 
-fn add_disciplina_to_local_storage(disciplina: Disciplina) {
-    let mut disciplinas: Vec<Disciplina> = get_saved_disciplines();
-    disciplinas.push(disciplina);
-    LocalStorage::set::<Vec<Disciplina>>("disciplinas", disciplinas).unwrap();
-}
+/// Chave do `Documento` versionado no LocalStorage.
+const DOCUMENT_KEY: &str = "cronograma";
+/// Chave antiga, onde ficava a lista de disciplinas sem versão.
+const LEGACY_KEY: &str = "disciplinas";
+/// Chave onde fica uma cópia do que estava salvo e não pôde ser lido.
+const BACKUP_KEY: &str = "cronograma-nao-lido";
+
+/// Carrega o documento salvo e remonta o cronograma, migrando a lista antiga de disciplinas se ainda não houver
+/// um documento.
+///
+/// Se o que estava salvo não puder ser lido ou remontado, ele é copiado para `BACKUP_KEY` antes de sair da chave
+/// original e o erro é mostrado: assim o próximo salvamento, que parte de um documento vazio, não o destrói.
+fn load_document() -> (Documento, Schedule) {
+    let salvo = [DOCUMENT_KEY, LEGACY_KEY]
+        .into_iter()
+        .find_map(|chave| {
+            LocalStorage::get::<serde_json::Value>(chave)
+                .ok()
+                .map(|valor| (chave, valor))
+        });
 
-pub fn get_saved_disciplines() -> Vec<Disciplina> {
-    match LocalStorage::get("disciplinas") {
-        Ok(val) => val,
-        Err(_) => {
-            LocalStorage::set::<Vec<Disciplina>>("disciplinas", vec![]).unwrap();
-            LocalStorage::get("disciplinas").unwrap()
+    let Some((chave, valor)) = salvo else {
+        return (Documento::new(&Schedule::new()), Schedule::new());
+    };
+
+    let carregado = Documento::from_value(valor.clone()).and_then(|documento| {
+        documento
+            .to_schedule()
+            .map(|schedule| (documento, schedule))
+    });
+
+    match carregado {
+        Ok(carregado) => carregado,
+        Err(error) => {
+            log!(&format!("Failed to load saved schedule: {:?}", error));
+            LocalStorage::set(BACKUP_KEY, &valor).expect("Failed to update LocalStorage");
+            LocalStorage::delete(chave);
+            gloo::dialogs::alert(&format!("Não foi possível carregar o cronograma salvo ({:?}). Uma cópia dele foi guardada em \"{}\" no LocalStorage.", error, BACKUP_KEY));
+            (Documento::new(&Schedule::new()), Schedule::new())
         }
     }
 }
 
-fn calculate_total_hours() -> usize {
-    let disciplinas = get_saved_disciplines();
-    let mut output = 0;
-    for dis in &disciplinas {
-        output += dis.sigaa_time.len() * 30;
-    }
-    output
+/// Salva o cronograma e o histórico de edições, mantendo os demais dados do documento.
+fn save_schedule(schedule: &Schedule, historico: &Historico) {
+    let (mut documento, _) = load_document();
+    documento.set_schedule(schedule);
+    documento.historico = historico.clone();
+    save_document(&documento);
+}
+
+/// Salva o documento. A lista antiga sai do LocalStorage aqui porque `load_document` só a deixa no lugar se ela
+/// tiver sido migrada para o documento.
+fn save_document(documento: &Documento) {
+    LocalStorage::set(DOCUMENT_KEY, documento.to_value()).expect("Failed to update LocalStorage");
+    LocalStorage::delete(LEGACY_KEY);
+}
+
+fn calculate_total_hours(schedule: &Schedule, tabela: &TabelaHorarios, dias: &DiasLetivos) -> usize {
+    schedule
+        .workload(tabela, dias)
+        .horas_periodo()
+        .round() as usize
 }
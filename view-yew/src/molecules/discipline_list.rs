@@ -1,10 +1,16 @@
 use super::super::atoms::discipline::DisciplineButton;
 use class::Disciplina;
+use schedule::DiasLetivos;
+use stf::TabelaHorarios;
 use yew::*;
 
 #[derive(Properties, PartialEq)]
 pub struct DisciplineListProps {
     pub discipline_list: Vec<Disciplina>,
+    /// Horários de início e fim dos períodos, para a carga horária de cada disciplina.
+    pub tabela: TabelaHorarios,
+    /// Aulas de cada dia no semestre do documento.
+    pub dias: DiasLetivos,
     pub on_remove: Callback<Disciplina>, // Callback para remover a disciplina
 }
 
@@ -16,7 +22,7 @@ pub fn DisciplineListComponent(props: &DisciplineListProps) -> Html {
                 {for props.discipline_list.iter().enumerate().map(|(index, disciplina)| {
 
                     html! {
-                        <DisciplineButton key={index} disciplina={disciplina.clone()} onremove={props.on_remove.clone()}/>
+                        <DisciplineButton key={index} disciplina={disciplina.clone()} tabela={props.tabela.clone()} dias={props.dias} onremove={props.on_remove.clone()}/>
                     }
                 })}
             </ul>
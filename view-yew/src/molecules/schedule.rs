@@ -11,8 +11,12 @@ pub struct ScheduleProps {
 
 #[function_component]
 pub fn ScheduleComponent(props: &ScheduleProps) -> Html {
-    let x: Vec<Vec<Html>> = (0..8)
-        .map(|x| create_row(x, &props.schedule))
+    let x: Vec<Vec<Html>> = props
+        .schedule
+        .format()
+        .turnos()
+        .iter()
+        .map(|turno| create_row(*turno, &props.schedule))
         .collect();
 
     html! {
@@ -32,14 +36,7 @@ pub fn ScheduleComponent(props: &ScheduleProps) -> Html {
     }
 }
 
-pub fn to_stf((x, y): (usize, usize)) -> SigaaTime {
-    let turno: Turno = x.try_into().unwrap();
-    let dia: Dia = y.try_into().unwrap();
-
-    SigaaTime::new(dia, turno)
-}
-
-pub fn to_schedule_unity((siga_time, schedule): (SigaaTime, &Schedule)) -> &ScheduleUnity {
+pub fn to_schedule_unity((siga_time, schedule): (SigaaTime, &Schedule)) -> Option<&ScheduleUnity> {
     schedule.get(&siga_time)
 }
 
@@ -47,12 +44,14 @@ pub fn to_html(unity: &ScheduleUnity) -> Html {
     html! { <UnityComponent props={unity.clone()}/> }
 }
 
-pub fn create_row(row: usize, schedule: &Schedule) -> Vec<Html> {
-    (0..6)
-        .map(|col| (row, col))
-        .map(to_stf)
+pub fn create_row(turno: Turno, schedule: &Schedule) -> Vec<Html> {
+    schedule
+        .format()
+        .dias()
+        .iter()
+        .map(|dia| SigaaTime::new(*dia, turno))
         .map(|x| (x, schedule))
-        .map(to_schedule_unity)
+        .filter_map(to_schedule_unity)
         .map(to_html)
         .collect::<Vec<Html>>()
 }